@@ -11,8 +11,8 @@ fn sim200x200(steps: u32) -> () {
     let fit_sprite = Sprite::load("assets/fit_pixel_blue.png");
     if let Ok(sprite) = fit_sprite {
         sim.insert_sprite(sprite, &Offset::new(80, 50), |color| match color {
-            0xFFFFFFFF => Sand::new(),
-            _ => Water::new(),
+            0xFFFFFFFF => Some(Sand::new()),
+            _ => Some(Water::new()),
         });
     }
 
@@ -3,25 +3,27 @@ use std::{collections::LinkedList, i32};
 
 use rustc_hash::FxHashSet;
 
-use crate::{particles::constants::UP, Offset};
+use crate::{Gravity, Offset};
 
 /// A collection of Offsets where they form a continuoes plane.
 pub struct Area {
     offsets: FxHashSet<Offset>,
-    // Due to coordinate system with (0,0) in top-left, the highest point is the one with the smallest y-value
+    gravity: Gravity,
+    // The highest point is the one that is the least deep in the direction of the gravity
     highest_point: i32,
 }
 
 impl Area {
-    pub fn new() -> Self {
+    pub fn new(gravity: Gravity) -> Self {
         Area {
             offsets: FxHashSet::default(),
+            gravity,
             highest_point: i32::max_value(),
         }
     }
 
     pub fn add(&mut self, offset: Offset) -> () {
-        self.highest_point = self.highest_point.min(offset.y);
+        self.highest_point = self.highest_point.min(self.gravity.depth_of(&offset));
         self.offsets.insert(offset);
     }
 
@@ -40,20 +42,22 @@ impl Area {
     }
 
     pub fn depth(&self, offset: &Offset) -> i32 {
-        offset.y - self.highest_point
+        self.gravity.depth_of(offset) - self.highest_point
     }
 
     pub fn get_heighest_offsets(&self) -> LinkedList<&Offset> {
         self.offsets
             .par_iter()
-            .filter(|off| off.y == self.highest_point)
+            .filter(|off| self.gravity.depth_of(off) == self.highest_point)
             .collect()
     }
 
     pub fn get_top_edge_offsets(&self) -> LinkedList<&Offset> {
+        let up = self.gravity.up();
+
         self.offsets
             .par_iter()
-            .filter(|off| !self.offsets.contains(&(**off + UP)))
+            .filter(|off| !self.offsets.contains(&(**off + up)))
             .collect()
     }
 }
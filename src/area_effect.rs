@@ -0,0 +1,35 @@
+use crate::offset::Offset;
+use crate::particles::Particle;
+
+/// An effect of a particle reaching beyond its neighborhood.
+/// Returned by the particle together with its new state and applied by the simulation after all particles are updated.
+#[derive(Clone)]
+pub enum AreaEffect {
+    /// Destroys particles in the `radius` and fills part of the crater with sparks and smoke.
+    /// Explosives in the `radius` are set off instead, so explosions can chain.
+    /// Moveable particles up to twice the `radius` away are pushed outwards by the `force`, weakening with distance.
+    /// Particles that block explosions (like rock) are not destroyed and shield the particles behind them.
+    Explosion { radius: u32, force: f32 },
+    /// Replaces the particle on the `offset` (relative to the particle causing the effect) with the `particle`, None removes it.
+    /// Nothing happens if the cell is empty or out of the grid.
+    Replace {
+        offset: Offset,
+        particle: Option<Box<dyn Particle>>,
+    },
+    /// Replaces the particle on the `offset` with the `particle` like `Replace`, but only if the `condition` holds for it.
+    /// Otherwise the particle causing the effect is set to the `fallback`, so it can take back its change.
+    /// The fallback is skipped if an earlier effect in the same step already changed the cell of the particle.
+    /// Protects against more particles reacting with the same cell in one step.
+    ReplaceIf {
+        offset: Offset,
+        condition: fn(&Box<dyn Particle>) -> bool,
+        particle: Option<Box<dyn Particle>>,
+        fallback: Option<Box<dyn Particle>>,
+    },
+    /// Places the `particle` on the `offset` (relative to the particle causing the effect).
+    /// Nothing happens if the cell is already taken or out of the grid.
+    Spawn {
+        offset: Offset,
+        particle: Box<dyn Particle>,
+    },
+}
@@ -3,12 +3,9 @@
 
 use eframe::egui;
 use particle_simulation::{
-    particles::{
-        constants::CELL_DEFAULT_PRESSURE, Fly, Mud, Oil, Particle, Rock, Sand, Smoke, Spark, Vapor,
-        Water, Wood,
-    },
+    particles::{constants::CELL_DEFAULT_PRESSURE, Particle, PARTICLE_TYPES},
     utility::get_offsets_for_square,
-    Color, Offset, Simulation,
+    Color, Gravity, Offset, PasteMode, Rect, Region, Simulation,
 };
 
 const SIM_WIDTH: usize = 200;
@@ -21,10 +18,27 @@ enum ViewMode {
     Pressure,
 }
 
+/// What happens when the left mouse button is pressed in the simulation view
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Tool {
+    Brush,
+    Select,
+    Paste,
+}
+
 fn color_to_color32(c: &Color) -> egui::Color32 {
     egui::Color32::from_rgba_unmultiplied(c.r, c.g, c.b, c.a)
 }
 
+/// Blends the color over the opaque background, so see-through particles show the background behind them.
+fn blend_over(c: &Color, bg: egui::Color32) -> egui::Color32 {
+    let alpha = c.a as u32;
+    let blend =
+        |front: u8, back: u8| ((front as u32 * alpha + back as u32 * (255 - alpha)) / 255) as u8;
+
+    egui::Color32::from_rgb(blend(c.r, bg.r()), blend(c.g, bg.g()), blend(c.b, bg.b()))
+}
+
 fn main() {
     let native_options = eframe::NativeOptions::default();
     let _ = eframe::run_native(
@@ -46,27 +60,28 @@ struct GUIParticleSim {
     /// The resulting are will be this value squared
     brush_size: u32,
     view_mode: ViewMode,
+    /// Angle of the gravity in degrees, 0 is down
+    gravity_angle: f32,
+    /// Strength of the gravity, 0 is zero-g
+    gravity_strength: f32,
+    tool: Tool,
+    /// Corner where the current selection started
+    selection_start: Option<Offset>,
+    selection: Option<Rect>,
+    /// Last copied region, pasted with the paste tool
+    clipboard: Option<Region>,
+    paste_mode: PasteMode,
 }
 
 impl GUIParticleSim {
     fn new(cc: &eframe::CreationContext<'_>) -> Self {
         let simulation = Simulation::new(SIM_WIDTH, SIM_HEIGHT);
+        let gravity = *simulation.gravity();
 
         // Function to call to create a new particle of type
-        let particles_new_functions = vec![
-            Sand::new,
-            Water::new,
-            Rock::new,
-            Mud::new,
-            Oil::new,
-            Wood::new,
-            Spark::new,
-            Fly::new,
-            Smoke::new,
-            Vapor::new,
-        ];
+        let particles_new_functions = PARTICLE_TYPES.iter().map(|t| t.new).collect();
         // Create preview particles by mapping the new functions
-        let preview_particles = particles_new_functions.iter().map(|f| f()).collect();
+        let preview_particles = PARTICLE_TYPES.iter().map(|t| (t.new)()).collect();
 
         GUIParticleSim {
             simulation,
@@ -81,6 +96,13 @@ impl GUIParticleSim {
             selected_particle_index: 0,
             brush_size: 4,
             view_mode: ViewMode::Normal,
+            gravity_angle: gravity.angle(),
+            gravity_strength: gravity.strength(),
+            tool: Tool::Brush,
+            selection_start: None,
+            selection: None,
+            clipboard: None,
+            paste_mode: PasteMode::Merge,
         }
     }
 }
@@ -104,12 +126,38 @@ impl eframe::App for GUIParticleSim {
                             pos_relative_to_view.y as i32,
                         );
 
-                        // Spawn particles in a square around the center with length of side equal to brush_size
-                        for off in get_offsets_for_square(&center, self.brush_size) {
-                            self.simulation.add_particle(
-                                &off,
-                                self.particles_new_functions[self.selected_particle_index](), // Call the new function of the currently selected particle
-                            );
+                        match self.tool {
+                            Tool::Brush => {
+                                // Spawn particles in a square around the center with length of side equal to brush_size
+                                for off in get_offsets_for_square(&center, self.brush_size) {
+                                    self.simulation.add_particle(
+                                        &off,
+                                        self.particles_new_functions[self.selected_particle_index](
+                                        ), // Call the new function of the currently selected particle
+                                    );
+                                }
+                            }
+                            Tool::Select => {
+                                // Start the selection on press and stretch it while the button is held
+                                if input.pointer.primary_pressed() {
+                                    self.selection_start = Some(center);
+                                }
+                                if let Some(start) = self.selection_start {
+                                    self.selection = Some(Rect::from_corners(&start, &center));
+                                }
+                            }
+                            Tool::Paste => {
+                                // Paste only once per press, with top left corner of the region under the mouse
+                                if input.pointer.primary_pressed() {
+                                    if let Some(region) = &self.clipboard {
+                                        self.simulation.paste_region(
+                                            region,
+                                            &center,
+                                            self.paste_mode,
+                                        );
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -137,12 +185,12 @@ impl eframe::App for GUIParticleSim {
             let bg = egui::Color32::LIGHT_BLUE;
 
             // Map simulation based on view mode
-            let pixels: Vec<egui::Color32> = match self.view_mode {
+            let mut pixels: Vec<egui::Color32> = match self.view_mode {
                 ViewMode::Normal => self
                     .simulation
                     .cells_iter()
                     .map(|cell| match cell.get_particle() {
-                        Some(p) => color_to_color32(p.get_color()),
+                        Some(p) => blend_over(p.get_color(), bg),
                         None => bg,
                     })
                     .collect(),
@@ -159,6 +207,21 @@ impl eframe::App for GUIParticleSim {
                     .collect(),
             };
 
+            // Outline the selection
+            if let Some(selection) = self.selection {
+                let right = selection.offset.x + selection.width as i32 - 1;
+                let bottom = selection.offset.y + selection.height as i32 - 1;
+                for off in selection.offsets() {
+                    let on_border = off.x == selection.offset.x
+                        || off.y == selection.offset.y
+                        || off.x == right
+                        || off.y == bottom;
+                    if on_border && off.x < SIM_WIDTH as i32 && off.y < SIM_HEIGHT as i32 {
+                        pixels[off.y as usize * SIM_WIDTH + off.x as usize] = egui::Color32::WHITE;
+                    }
+                }
+            }
+
             // Draw pixels to texture
             self.texture.set(
                 egui::ColorImage {
@@ -188,6 +251,22 @@ impl eframe::App for GUIParticleSim {
                 // Add brush size slider
                 cols[1].add(egui::Slider::new(&mut self.brush_size, 1..=20).text("Brush size"));
 
+                // Add gravity sliders, the gravity is changed only if any of them was moved
+                let angle_response = cols[1].add(
+                    egui::Slider::new(&mut self.gravity_angle, -180.0..=180.0)
+                        .text("Gravity angle"),
+                );
+                let strength_response = cols[1].add(
+                    egui::Slider::new(&mut self.gravity_strength, 0.0..=0.5)
+                        .text("Gravity strength"),
+                );
+                if angle_response.changed() || strength_response.changed() {
+                    self.simulation.set_gravity(Gravity::from_angle(
+                        self.gravity_angle,
+                        self.gravity_strength,
+                    ));
+                }
+
                 egui::ComboBox::from_label("View mode")
                     .selected_text(format!("{:?}", self.view_mode))
                     .show_ui(&mut cols[1], |ui| {
@@ -195,6 +274,64 @@ impl eframe::App for GUIParticleSim {
                         ui.selectable_value(&mut self.view_mode, ViewMode::Pressure, "Pressure");
                     });
 
+                // Add tools for copying and pasting parts of the simulation
+                egui::ComboBox::from_label("Tool")
+                    .selected_text(format!("{:?}", self.tool))
+                    .show_ui(&mut cols[1], |ui| {
+                        ui.selectable_value(&mut self.tool, Tool::Brush, "Brush");
+                        ui.selectable_value(&mut self.tool, Tool::Select, "Select");
+                        ui.selectable_value(&mut self.tool, Tool::Paste, "Paste");
+                    });
+                egui::ComboBox::from_label("Paste mode")
+                    .selected_text(format!("{:?}", self.paste_mode))
+                    .show_ui(&mut cols[1], |ui| {
+                        ui.selectable_value(
+                            &mut self.paste_mode,
+                            PasteMode::Overwrite,
+                            "Overwrite",
+                        );
+                        ui.selectable_value(&mut self.paste_mode, PasteMode::Merge, "Merge");
+                        ui.selectable_value(
+                            &mut self.paste_mode,
+                            PasteMode::FillEmpty,
+                            "FillEmpty",
+                        );
+                    });
+                cols[1].horizontal(|ui| {
+                    // Copy the selection and switch to pasting it
+                    if ui
+                        .add_enabled(self.selection.is_some(), egui::Button::new("Copy"))
+                        .clicked()
+                    {
+                        self.clipboard = self
+                            .selection
+                            .map(|selection| self.simulation.extract_region(&selection));
+                        self.selection = None;
+                        self.tool = Tool::Paste;
+                    }
+
+                    // Transform the copied region
+                    let has_clipboard = self.clipboard.is_some();
+                    if ui
+                        .add_enabled(has_clipboard, egui::Button::new("Rotate"))
+                        .clicked()
+                    {
+                        self.clipboard = self.clipboard.as_ref().map(|region| region.rotated(1));
+                    }
+                    if ui
+                        .add_enabled(has_clipboard, egui::Button::new("Flip H"))
+                        .clicked()
+                    {
+                        self.clipboard = self.clipboard.as_ref().map(Region::flipped_horizontally);
+                    }
+                    if ui
+                        .add_enabled(has_clipboard, egui::Button::new("Flip V"))
+                        .clicked()
+                    {
+                        self.clipboard = self.clipboard.as_ref().map(Region::flipped_vertically);
+                    }
+                });
+
                 // Add label for particles
                 cols[1].add(egui::Label::new("Particles"));
 
@@ -0,0 +1,37 @@
+use crate::{Offset, Rect};
+
+/// Connected group of particles of the same type, found by `Simulation::find_blob`.
+#[derive(Clone, Debug)]
+pub struct Blob {
+    /// Offsets of all particles in the blob, never empty
+    pub offsets: Vec<Offset>,
+}
+
+impl Blob {
+    /// Returns the number of particles in the blob.
+    pub fn size(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns the smallest rectangle containing the whole blob.
+    pub fn bounding_box(&self) -> Rect {
+        let min = self.offsets.iter().fold(self.offsets[0], |acc, off| {
+            Offset::new(acc.x.min(off.x), acc.y.min(off.y))
+        });
+        let max = self.offsets.iter().fold(self.offsets[0], |acc, off| {
+            Offset::new(acc.x.max(off.x), acc.y.max(off.y))
+        });
+
+        Rect::from_corners(&min, &max)
+    }
+
+    /// Returns the average position of the particles in the blob as (x, y).
+    pub fn centroid(&self) -> (f32, f32) {
+        let (sum_x, sum_y) = self.offsets.iter().fold((0.0, 0.0), |(x, y), off| {
+            (x + off.x as f32, y + off.y as f32)
+        });
+        let count = self.offsets.len() as f32;
+
+        (sum_x / count, sum_y / count)
+    }
+}
@@ -30,6 +30,10 @@ impl Cell {
         &self.particle
     }
 
+    pub fn get_particle_mut(&mut self) -> &mut Option<Box<dyn Particle>> {
+        &mut self.particle
+    }
+
     pub fn set_particle(&mut self, new_particle: Box<dyn Particle>) -> () {
         self.particle = Some(new_particle)
     }
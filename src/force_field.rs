@@ -0,0 +1,136 @@
+use crate::offset::Offset;
+use crate::particles::constants::MAX_GAS_DENSITY;
+use crate::rect::Rect;
+use crate::Velocity;
+
+/// How does the force of a field weaken with the distance from its center.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Falloff {
+    /// The same force in the whole field.
+    None,
+    /// The force decreases linearly to zero at the edge of the field.
+    Linear,
+    /// The force decreases quadratically to zero at the edge of the field.
+    Quadratic,
+}
+
+impl Falloff {
+    /// Returns the multiplier of the force for the `distance` from the center of a field with the `radius`.
+    pub fn multiplier(&self, distance: f32, radius: f32) -> f32 {
+        if radius <= 0.0 || distance > radius {
+            return 0.0;
+        }
+
+        let rest = 1.0 - distance / radius;
+        match self {
+            Falloff::None => 1.0,
+            Falloff::Linear => rest,
+            Falloff::Quadratic => rest * rest,
+        }
+    }
+}
+
+/// Shape of the force field and direction of its force.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldKind {
+    /// Pushes particles in the `direction` over the whole rectangle.
+    Wind { area: Rect, direction: Velocity },
+    /// Pulls particles towards the center. Negative strength pushes them away instead.
+    Radial { center: Offset, radius: f32 },
+    /// Spins particles around the center. Positive strength spins them clockwise.
+    Vortex { center: Offset, radius: f32 },
+}
+
+/// An external force acting on moveable particles.
+/// Lighter particles are affected more, gases the most.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ForceField {
+    pub kind: FieldKind,
+    /// Change of velocity per update at the strongest point of the field
+    pub strength: f32,
+    pub falloff: Falloff,
+}
+
+impl ForceField {
+    /// Uniform wind over the `area` blowing in the `direction`.
+    pub fn wind(area: Rect, direction: Velocity, strength: f32) -> ForceField {
+        ForceField {
+            kind: FieldKind::Wind { area, direction },
+            strength,
+            falloff: Falloff::None,
+        }
+    }
+
+    /// Pulls particles in the `radius` towards the `center`. The force weakens linearly with distance.
+    pub fn attractor(center: Offset, radius: f32, strength: f32) -> ForceField {
+        ForceField {
+            kind: FieldKind::Radial { center, radius },
+            strength,
+            falloff: Falloff::Linear,
+        }
+    }
+
+    /// Pushes particles in the `radius` away from the `center`. The force weakens linearly with distance.
+    pub fn repulsor(center: Offset, radius: f32, strength: f32) -> ForceField {
+        ForceField::attractor(center, radius, -strength)
+    }
+
+    /// Spins particles in the `radius` clockwise around the `center`. The force weakens linearly with distance.
+    pub fn vortex(center: Offset, radius: f32, strength: f32) -> ForceField {
+        ForceField {
+            kind: FieldKind::Vortex { center, radius },
+            strength,
+            falloff: Falloff::Linear,
+        }
+    }
+
+    /// Returns the same field with a different falloff.
+    pub fn with_falloff(mut self, falloff: Falloff) -> ForceField {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Returns the force of the field on the `offset`, not yet scaled by the density of a particle.
+    pub fn force_at(&self, offset: &Offset) -> Velocity {
+        match self.kind {
+            FieldKind::Wind { area, direction } => {
+                if !area.contains(offset) {
+                    return Velocity::zero();
+                }
+
+                // Weakens from the center of the area towards its corners
+                let center = area.center();
+                let radius = Velocity::new(area.width as f32, area.height as f32).magnitude() / 2.0;
+                let distance = distance(offset, &center);
+
+                direction.with_magnitude(self.strength * self.falloff.multiplier(distance, radius))
+            }
+            FieldKind::Radial { center, radius } => {
+                let to_center =
+                    Velocity::new((center.x - offset.x) as f32, (center.y - offset.y) as f32);
+                let multiplier = self.falloff.multiplier(to_center.magnitude(), radius);
+
+                to_center.with_magnitude(self.strength * multiplier)
+            }
+            FieldKind::Vortex { center, radius } => {
+                let to_center =
+                    Velocity::new((center.x - offset.x) as f32, (center.y - offset.y) as f32);
+                let multiplier = self.falloff.multiplier(to_center.magnitude(), radius);
+                // Perpendicular to the direction to the center
+                let tangent = Velocity::new(to_center.y, -to_center.x);
+
+                tangent.with_magnitude(self.strength * multiplier)
+            }
+        }
+    }
+}
+
+/// Returns how much is a particle of `density` affected by forces.
+/// Gases are affected fully, heavier particles proportionally less.
+pub fn density_factor(density: u8) -> f32 {
+    MAX_GAS_DENSITY as f32 / density.max(MAX_GAS_DENSITY) as f32
+}
+
+fn distance(a: &Offset, b: &Offset) -> f32 {
+    Velocity::new((a.x - b.x) as f32, (a.y - b.y) as f32).magnitude()
+}
@@ -0,0 +1,199 @@
+use std::f32::consts::FRAC_PI_4;
+
+use crate::offset::Offset;
+use crate::particles::constants::*;
+use crate::Velocity;
+
+/// The 8 directions ordered so that each next one is rotated by 45 degrees from the previous one, starting with down.
+const DIRECTIONS: [Offset; 8] = [
+    Offset { x: 0, y: 1 },
+    Offset { x: -1, y: 1 },
+    Offset { x: -1, y: 0 },
+    Offset { x: -1, y: -1 },
+    Offset { x: 0, y: -1 },
+    Offset { x: 1, y: -1 },
+    Offset { x: 1, y: 0 },
+    Offset { x: 1, y: 1 },
+];
+
+/// Direction and strength of the gravity in the simulation.
+/// Particles ask for the directions relative to the gravity (down, left, ...) instead of using the grid directions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gravity {
+    /// Unit vector pointing down
+    direction: Velocity,
+    strength: f32,
+}
+
+impl Gravity {
+    /// Creates gravity pulling in the `direction` with the `strength` (acceleration per update).
+    /// Zero direction or strength results in zero-g.
+    pub fn new(direction: Velocity, strength: f32) -> Gravity {
+        let direction = direction.with_magnitude(1.0);
+
+        if direction.is_zero() || strength <= 0.0 {
+            return Gravity::zero();
+        }
+
+        Gravity {
+            direction,
+            strength,
+        }
+    }
+
+    /// Creates gravity from an angle in degrees. The angle of 0 points down, 90 points left and so on.
+    pub fn from_angle(degrees: f32, strength: f32) -> Gravity {
+        let radians = degrees.to_radians();
+
+        Gravity::new(Velocity::new(-radians.sin(), radians.cos()), strength)
+    }
+
+    /// No gravity at all, particles only keep their momentum.
+    pub fn zero() -> Gravity {
+        Gravity {
+            direction: Velocity::zero(),
+            strength: 0.0,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.strength == 0.0
+    }
+
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    /// Returns the unit vector pointing in the direction of the gravity.
+    pub fn direction(&self) -> Velocity {
+        self.direction
+    }
+
+    /// Returns the angle of the gravity in degrees, see `Gravity::from_angle`.
+    pub fn angle(&self) -> f32 {
+        (-self.direction.x).atan2(self.direction.y).to_degrees()
+    }
+
+    /// Returns the change of velocity caused by the gravity in one update.
+    pub fn acceleration(&self) -> Velocity {
+        self.direction * self.strength
+    }
+
+    /// Returns the neighboring offset closest to the direction of the gravity. Zero offset in case of zero-g.
+    pub fn down(&self) -> Offset {
+        self.rotated_down(0)
+    }
+
+    pub fn up(&self) -> Offset {
+        self.rotated_down(4)
+    }
+
+    /// Left side when looking in the direction opposite of the gravity.
+    pub fn left(&self) -> Offset {
+        self.rotated_down(2)
+    }
+
+    /// Right side when looking in the direction opposite of the gravity.
+    pub fn right(&self) -> Offset {
+        self.rotated_down(6)
+    }
+
+    pub fn down_left(&self) -> Offset {
+        self.rotated_down(1)
+    }
+
+    pub fn down_right(&self) -> Offset {
+        self.rotated_down(7)
+    }
+
+    /// Returns the side offset, `left` for negative `side` and `right` for positive.
+    pub fn side(&self, side: i32) -> Offset {
+        if side < 0 {
+            self.left()
+        } else {
+            self.right()
+        }
+    }
+
+    /// Returns the down side offset, `down_left` for negative `side` and `down_right` for positive.
+    pub fn down_side(&self, side: i32) -> Offset {
+        if side < 0 {
+            self.down_left()
+        } else {
+            self.down_right()
+        }
+    }
+
+    /// True if the offset is aiming in the direction of the gravity.
+    pub fn is_down(&self, offset: &Offset) -> bool {
+        !self.is_zero() && *offset == self.down()
+    }
+
+    /// Returns how far in the direction of the gravity the offset is. Used to measure depth.
+    pub fn depth_of(&self, offset: &Offset) -> i32 {
+        let down = self.down();
+
+        offset.x * down.x + offset.y * down.y
+    }
+
+    /// Returns velocity of a freely falling particle after one update.
+    /// Particles that are not already falling start with the default velocity, rising particles are slowed down.
+    /// In zero-g the particle keeps drifting, only slowed down by the air.
+    pub fn fall(&self, velocity: &Velocity) -> Velocity {
+        let (along, across) = self.split(velocity);
+        let across = across * (1.0 - AIR_RESISTANCE);
+
+        if self.is_zero() {
+            return across;
+        }
+
+        let along = if along >= 0.0 {
+            along.max(DEFAULT_VELOCITY)
+        } else {
+            along
+        };
+
+        self.direction * (along + self.strength).min(MAX_VELOCITY) + across
+    }
+
+    /// Returns velocity of a particle resting on something after one update.
+    /// The velocity in the direction of the gravity is lost and the rest is slowed down by friction until it stops.
+    /// In zero-g nothing holds the particle, so it keeps drifting as in `fall`.
+    pub fn rest(&self, velocity: &Velocity) -> Velocity {
+        if self.is_zero() {
+            return self.fall(velocity);
+        }
+
+        let (_, across) = self.split(velocity);
+
+        across.slowed_down(GROUND_FRICTION)
+    }
+
+    /// Splits the velocity into the magnitude along the gravity and the velocity across it.
+    fn split(&self, velocity: &Velocity) -> (f32, Velocity) {
+        let along = velocity.x * self.direction.x + velocity.y * self.direction.y;
+        let across = *velocity - self.direction * along;
+
+        (along, across)
+    }
+
+    /// Returns direction that is rotated by `eighths` of the full circle from down.
+    fn rotated_down(&self, eighths: usize) -> Offset {
+        if self.is_zero() {
+            return Offset::zero();
+        }
+
+        let angle = (-self.direction.x).atan2(self.direction.y);
+        let index = (angle / FRAC_PI_4).round() as i32;
+        let index = (index + eighths as i32).rem_euclid(DIRECTIONS.len() as i32);
+
+        DIRECTIONS[index as usize]
+    }
+}
+
+impl Default for Gravity {
+    /// Gravity pulling towards the bottom of the grid.
+    fn default() -> Self {
+        Gravity::new(Velocity::new(0.0, 1.0), GRAVITY)
+    }
+}
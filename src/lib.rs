@@ -2,17 +2,35 @@
 #[macro_use]
 pub mod utility;
 mod area;
+mod area_effect;
+mod blob;
 mod cell;
 mod color;
+mod force_field;
+mod gravity;
 mod neighborhood;
 mod offset;
+mod palette;
 pub mod particles;
+mod rect;
+mod region;
+#[cfg(feature = "scripting")]
+pub mod scripting;
 mod simulation;
 mod sprite;
+mod velocity;
 
+pub use area_effect::AreaEffect;
+pub use blob::Blob;
 pub use cell::Cell;
 pub use color::Color;
+pub use force_field::{Falloff, FieldKind, ForceField};
+pub use gravity::Gravity;
 pub use neighborhood::Neighborhood;
 pub use offset::Offset;
+pub use palette::{Palette, PaletteError};
+pub use rect::Rect;
+pub use region::{PasteMode, Region};
 pub use simulation::Simulation;
 pub use sprite::Sprite;
+pub use velocity::Velocity;
@@ -1,52 +1,135 @@
 use crate::offset::Offset;
-use crate::Cell;
+use crate::{Cell, Gravity};
 
-/// Hold cell in a 3x3 neighborhood. None means the cell is out of the grid.
-pub struct Neighborhood<'a>(pub Vec<Vec<Option<&'a Cell>>>);
+/// A view of the cells around a particle, up to the `radius` in each direction (3x3 for radius of 1).
+/// Borrows the cells of the simulation, so creating it does not allocate.
+/// None means the cell is out of the grid (or out of the radius).
+/// Also holds the gravity of the simulation, so particles know where "down" is.
+/// Directions like `up` or `down` are in the grid, regardless of the gravity.
+pub struct Neighborhood<'a> {
+    cells: &'a [Cell],
+    width: usize,
+    height: usize,
+    center: Offset,
+    radius: u32,
+    gravity: Gravity,
+}
 
 impl<'a> Neighborhood<'a> {
-    pub fn up(&self) -> &Option<&Cell> {
-        &self.0[0][1]
+    /// Creates a view of the `cells` (a grid of `width` and `height`) around the `center`.
+    pub fn new(
+        cells: &'a [Cell],
+        width: usize,
+        height: usize,
+        center: Offset,
+        radius: u32,
+        gravity: Gravity,
+    ) -> Neighborhood<'a> {
+        Neighborhood {
+            cells,
+            width,
+            height,
+            center,
+            radius,
+            gravity,
+        }
+    }
+
+    pub fn gravity(&self) -> &Gravity {
+        &self.gravity
+    }
+
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    pub fn up(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(0, -1))
+    }
+
+    pub fn down(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(0, 1))
     }
 
-    pub fn down(&self) -> &Option<&Cell> {
-        &self.0[2][1]
+    pub fn left(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(-1, 0))
     }
 
-    pub fn left(&self) -> &Option<&Cell> {
-        &self.0[1][0]
+    pub fn right(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(1, 0))
     }
 
-    pub fn right(&self) -> &Option<&Cell> {
-        &self.0[1][2]
+    pub fn up_left(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(-1, -1))
     }
 
-    pub fn up_left(&self) -> &Option<&Cell> {
-        &self.0[0][0]
+    pub fn up_right(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(1, -1))
     }
 
-    pub fn up_right(&self) -> &Option<&Cell> {
-        &self.0[0][2]
+    pub fn down_left(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(-1, 1))
+    }
+
+    pub fn down_right(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::new(1, 1))
+    }
+
+    pub fn center(&self) -> Option<&'a Cell> {
+        self.on_relative(&Offset::zero())
+    }
+
+    /// Returns the cell on the `offset` relative to the center of neighborhood.
+    /// None if the cell is out of the grid or further than the radius.
+    pub fn on_relative(&self, offset: &Offset) -> Option<&'a Cell> {
+        let radius = self.radius as i32;
+        if offset.x.abs() > radius || offset.y.abs() > radius {
+            return None;
+        }
+
+        let absolute = self.center + *offset;
+        if absolute.x < 0
+            || absolute.y < 0
+            || absolute.x >= self.width as i32
+            || absolute.y >= self.height as i32
+        {
+            return None;
+        }
+
+        self.cells
+            .get(absolute.y as usize * self.width + absolute.x as usize)
     }
 
-    pub fn down_left(&self) -> &Option<&Cell> {
-        &self.0[2][0]
+    /// Iterates over all cells in the radius row by row, including the center.
+    /// Use `adjacent` for reactions with the direct neighbors only.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'a Cell>> + '_ {
+        self.iter_relative().map(|(_, opt)| opt)
     }
 
-    pub fn down_right(&self) -> &Option<&Cell> {
-        &self.0[2][2]
+    /// Iterates over all cells in the radius row by row, including the center, together with their relative offsets.
+    pub fn iter_relative(&self) -> impl Iterator<Item = (Offset, Option<&'a Cell>)> + '_ {
+        self.iter_relative_up_to(self.radius as i32)
     }
 
-    pub fn center(&self) -> &Option<&Cell> {
-        &self.0[1][1]
+    /// Iterates over the 3x3 cells around the center row by row, including the center, regardless of the radius.
+    pub fn adjacent(&self) -> impl Iterator<Item = Option<&'a Cell>> + '_ {
+        self.adjacent_relative().map(|(_, opt)| opt)
     }
 
-    /// Returns the Option on the `offset` relative to the center of neighborhood.
-    pub fn on_relative(&self, offset: &Offset) -> &Option<&Cell> {
-        &self.0[(1 + offset.y) as usize][(1 + offset.x) as usize]
+    /// Iterates over the 3x3 cells around the center as in `adjacent`, together with their relative offsets.
+    pub fn adjacent_relative(&self) -> impl Iterator<Item = (Offset, Option<&'a Cell>)> + '_ {
+        self.iter_relative_up_to(1)
     }
 
-    pub fn iter(&self) -> std::iter::Flatten<std::slice::Iter<'_, Vec<Option<&Cell>>>> {
-        self.0.iter().flatten()
+    fn iter_relative_up_to(
+        &self,
+        radius: i32,
+    ) -> impl Iterator<Item = (Offset, Option<&'a Cell>)> + '_ {
+        (-radius..=radius).flat_map(move |y| {
+            (-radius..=radius).map(move |x| {
+                let offset = Offset::new(x, y);
+                (offset, self.on_relative(&offset))
+            })
+        })
     }
 }
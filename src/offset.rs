@@ -15,38 +15,40 @@ impl Offset {
         Offset { x: 0, y: 0 }
     }
 
-    /// Returns all offsets between self and the other.
-    /// Panics if the offsets are not in straight nice line.
+    /// Returns all offsets between self and the other, including both of them.
+    /// The line is traced using the Bresenham's line algorithm, so it works in any direction.
     pub fn between(&self, other: &Offset) -> Vec<Offset> {
-        let x_diff = other.x - self.x;
-        let y_diff = other.y - self.y;
-
-        if !(x_diff.abs() == y_diff.abs() || x_diff == 0 || y_diff == 0) {
-            return vec![];
-        }
-
-        // Find the direction of the difference - in case of difference of 0 we just leave a 1
-        let x_sign = x_diff.signum();
-        let y_sign = y_diff.signum();
-
-        // If the X difference is 0, move only on the Y line
-        if x_diff == 0 {
-            return (0..=y_diff.abs())
-                .map(|s| Offset::new(self.x, self.y + s * y_sign))
-                .collect();
-        }
-
-        // If the Y difference is 0, move only on the X line
-        if y_diff == 0 {
-            return (0..=x_diff.abs())
-                .map(|s| Offset::new(self.x + s * x_sign, self.y))
-                .collect();
+        let x_diff = (other.x - self.x).abs();
+        let y_diff = -(other.y - self.y).abs();
+
+        // Find the direction of the difference
+        let x_sign = (other.x - self.x).signum();
+        let y_sign = (other.y - self.y).signum();
+
+        let mut offsets = Vec::with_capacity((x_diff.max(-y_diff) + 1) as usize);
+        let mut current = *self;
+        let mut error = x_diff + y_diff;
+
+        loop {
+            offsets.push(current);
+            if current == *other {
+                break;
+            }
+
+            let doubled_error = 2 * error;
+            // Step on the X axis
+            if doubled_error >= y_diff {
+                error += y_diff;
+                current.x += x_sign;
+            }
+            // Step on the Y axis
+            if doubled_error <= x_diff {
+                error += x_diff;
+                current.y += y_sign;
+            }
         }
 
-        // Else do combination of the both above
-        (0..=x_diff.abs()) // Could also be y_diff (they are the same)
-            .map(|s| Offset::new(self.x + s * x_sign, self.y + s * y_sign))
-            .collect()
+        offsets
     }
 
     /// Get the the offset where both axis are scaled to magnitude of 1
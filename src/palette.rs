@@ -0,0 +1,147 @@
+use std::fmt::Display;
+use std::fs::read_to_string;
+
+use crate::particles::{find_particle_type, Particle, ParticleType, PARTICLE_TYPES};
+use crate::Sprite;
+
+/// Error that may occur while loading a palette.
+#[derive(Debug)]
+pub enum PaletteError {
+    /// The palette file could not be read
+    Io(std::io::Error),
+    /// Line (numbered from 1) is not in the `#RRGGBB Name` format
+    InvalidLine(usize),
+    /// There is no particle type with this name
+    UnknownParticle(String),
+}
+
+impl Display for PaletteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaletteError::Io(err) => write!(f, "Could not read the palette: {}", err),
+            PaletteError::InvalidLine(line) => write!(f, "Invalid palette entry on line {}", line),
+            PaletteError::UnknownParticle(name) => write!(f, "Unknown particle \"{}\"", name),
+        }
+    }
+}
+
+impl std::error::Error for PaletteError {}
+
+/// Maps colors to particle types, used to translate sprites into particles.
+/// Colors that are not in the palette translate to the particle type with the nearest color.
+#[derive(Clone)]
+pub struct Palette {
+    /// Colors in RGB format and their particle types
+    entries: Vec<(u32, &'static ParticleType)>,
+}
+
+impl Palette {
+    /// Creates an empty palette.
+    pub fn new() -> Palette {
+        Palette {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Loads palette from a file. See `Palette::parse` for the format.
+    pub fn load(path: &str) -> Result<Palette, PaletteError> {
+        let text = read_to_string(path).map_err(PaletteError::Io)?;
+        Palette::parse(&text)
+    }
+
+    /// Parses palette from text. Each line maps a hex color to a particle name, for example `#326ECF Water`.
+    /// Empty lines and lines starting with `//` are ignored.
+    pub fn parse(text: &str) -> Result<Palette, PaletteError> {
+        let mut palette = Palette::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            // Split into color and the rest of the line, names may contain spaces
+            let (hex, name) = line
+                .split_once(char::is_whitespace)
+                .ok_or(PaletteError::InvalidLine(i + 1))?;
+            let hex = hex.trim_start_matches('#');
+            if hex.len() != 6 {
+                return Err(PaletteError::InvalidLine(i + 1));
+            }
+            let color =
+                u32::from_str_radix(hex, 16).map_err(|_| PaletteError::InvalidLine(i + 1))?;
+
+            palette.add(color, name.trim())?;
+        }
+
+        Ok(palette)
+    }
+
+    /// Maps the color in RGB format to a particle with the name.
+    pub fn add(&mut self, color: u32, name: &str) -> Result<(), PaletteError> {
+        let particle_type =
+            find_particle_type(name).ok_or(PaletteError::UnknownParticle(name.to_string()))?;
+        self.entries.push((color & 0xFFFFFF, particle_type));
+
+        Ok(())
+    }
+
+    /// Returns the particle type with color nearest to the given color. Alpha is ignored.
+    /// Returns None only if the palette is empty.
+    pub fn nearest(&self, color: u32) -> Option<&'static ParticleType> {
+        self.entries
+            .iter()
+            .min_by_key(|(c, _)| color_distance(*c, color))
+            .map(|(_, particle_type)| *particle_type)
+    }
+
+    /// Returns the color in RGB format that the particle with the name is mapped to.
+    /// If there are more such colors, the first one is returned.
+    pub fn color_of(&self, name: &str) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|(_, particle_type)| particle_type.name == name)
+            .map(|(color, _)| *color)
+    }
+
+    /// Translates the color in ARGB format to a new particle. Fully transparent colors are left empty.
+    pub fn translate(&self, color: u32) -> Option<Box<dyn Particle>> {
+        if Sprite::is_transparent(color) {
+            return None;
+        }
+
+        self.nearest(color)
+            .map(|particle_type| (particle_type.new)())
+    }
+}
+
+impl Default for Palette {
+    /// Palette of all particle types with their own colors.
+    fn default() -> Self {
+        Palette {
+            entries: PARTICLE_TYPES.iter().map(|t| (t.color, t)).collect(),
+        }
+    }
+}
+
+impl Display for Palette {
+    /// Writes the palette in the same format as is read by `Palette::parse`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (color, particle_type) in &self.entries {
+            writeln!(f, "#{:06X} {}", color, particle_type.name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Squared distance of the two colors in RGB space.
+fn color_distance(a: u32, b: u32) -> u32 {
+    [16, 8, 0]
+        .iter()
+        .map(|shift| {
+            let diff = ((a >> shift) & 0xFF).abs_diff((b >> shift) & 0xFF);
+            diff * diff
+        })
+        .sum()
+}
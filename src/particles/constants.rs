@@ -4,6 +4,12 @@ pub const DEFAULT_VELOCITY: f32 = 1.0;
 pub const MAX_VELOCITY: f32 = 5.0;
 pub const GRAVITY: f32 = 0.1;
 pub const SWITCH_SLOWDOWN: f32 = 0.1;
+/// Portion of the horizontal velocity lost each update while falling freely.
+pub const AIR_RESISTANCE: f32 = 0.02;
+/// Portion of the horizontal velocity lost each update while resting on something.
+pub const GROUND_FRICTION: f32 = 0.3;
+/// Velocities with smaller magnitude are considered as stopped.
+pub const MIN_VELOCITY: f32 = 0.2;
 pub const MAX_DENSITY: u8 = 255;
 pub const MAX_GAS_DENSITY: u8 = 64;
 pub const FIRE_COLOR: u32 = 0x940C0C;
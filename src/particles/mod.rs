@@ -1,27 +1,65 @@
 pub mod constants;
+mod p_acid;
+mod p_battery;
+mod p_concrete;
+mod p_fire;
+mod p_fish;
 mod p_fly;
+mod p_glass;
+mod p_gunpowder;
+mod p_ice;
+mod p_igniter;
+mod p_lamp;
+mod p_lava;
+mod p_metal;
 mod p_mud;
 mod p_oil;
+mod p_plant;
 mod p_rock;
+mod p_salt;
+mod p_salt_water;
 mod p_sand;
+mod p_seed;
 mod p_smoke;
+mod p_snow;
 mod p_spark;
+mod p_tnt;
 mod p_vapor;
 mod p_water;
 mod p_wood;
 mod particle;
 /// Various properties that a particle may inhibit.
 mod properties;
+mod registry;
 
+pub use p_acid::Acid;
+pub use p_battery::Battery;
+pub use p_concrete::Concrete;
+pub use p_fire::Fire;
+pub use p_fish::Fish;
 pub use p_fly::Fly;
+pub use p_glass::Glass;
+pub use p_gunpowder::Gunpowder;
+pub use p_ice::Ice;
+pub use p_igniter::Igniter;
+pub use p_lamp::Lamp;
+pub use p_lava::Lava;
+pub use p_metal::Metal;
 pub use p_mud::Mud;
 pub use p_oil::Oil;
+pub use p_plant::Plant;
 pub use p_rock::Rock;
+pub use p_salt::Salt;
+pub use p_salt_water::SaltWater;
 pub use p_sand::Sand;
+pub use p_seed::Seed;
 pub use p_smoke::Smoke;
+pub use p_snow::Snow;
 pub use p_spark::Spark;
+pub use p_tnt::Tnt;
 pub use p_vapor::Vapor;
 pub use p_water::Water;
 pub use p_wood::Wood;
 pub use particle::{MatterType, Particle, ParticleChange};
-pub use properties::Burnability;
+pub use properties::{Burnability, Conductivity, Corrodibility, Freezability};
+pub use registry::{find_particle_type, ParticleType, PARTICLE_TYPES};
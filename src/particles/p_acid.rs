@@ -0,0 +1,93 @@
+use fastrand;
+
+use crate::particles::constants::*;
+use crate::particles::{Particle, Smoke, Vapor};
+use crate::{Color, Neighborhood, Offset, Velocity};
+
+use super::p_water::LiquidMovement;
+use super::properties::PropertyCheckResult;
+use super::{Corrodibility, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0x8FD400;
+const DENSITY: u8 = 135;
+/// Number of updates the acid can corrode before it is used up
+const STRENGTH: u8 = 40;
+
+#[derive(Clone)]
+pub struct Acid {
+    flow: LiquidMovement,
+    color: Color,
+    corrodibility: Corrodibility,
+}
+
+impl Acid {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Acid {
+            color: Color::hex(COLOR).similiar(),
+            flow: LiquidMovement::new(),
+            corrodibility: Corrodibility::Corrosive(STRENGTH),
+        })
+    }
+}
+
+impl Particle for Acid {
+    fn get_name(&self) -> &str {
+        "Acid"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Liquid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_acid = self.clone();
+
+        // Used up by corroding, leaves fumes behind
+        if let PropertyCheckResult::Destroyed = Corrodibility::check(&mut new_acid, &neigborhood) {
+            if fastrand::bool() {
+                return ParticleChange::Changed(Some(Smoke::new()));
+            } else {
+                return ParticleChange::Changed(Some(Vapor::new()));
+            }
+        }
+
+        let side_speed = DEFAULT_VELOCITY
+            .max(new_acid.flow.velocity.magnitude())
+            .max(LiquidMovement::pressure_speed(&neigborhood));
+        new_acid.flow.update(self, &neigborhood, side_speed);
+
+        ParticleChange::Changed(Some(Box::new(new_acid)))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::{Color, Offset};
+
+use super::{Conductivity, MatterType};
+
+pub(super) const COLOR: u32 = 0x2E6B3A;
+const DENSITY: u8 = MAX_DENSITY;
+
+/// Source of charge for the conductors touching it.
+#[derive(Clone)]
+pub struct Battery {
+    color: Color,
+}
+
+impl Battery {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Battery {
+            color: Color::hex(COLOR).similiar(),
+        })
+    }
+}
+
+impl Particle for Battery {
+    fn get_name(&self) -> &str {
+        "Battery"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::Source
+    }
+
+    fn is_charged(&self) -> bool {
+        true
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+}
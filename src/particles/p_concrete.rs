@@ -0,0 +1,123 @@
+use crate::particles::constants::*;
+use crate::particles::{Particle, Water};
+use crate::{Color, Neighborhood, Offset, Velocity};
+
+use super::p_mud::SlurryMovement;
+use super::properties::PropertyCheckResult;
+use super::{Corrodibility, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xA8A39A;
+const SET_COLOR: u32 = 0x7D7B76;
+const DENSITY: u8 = MAX_DENSITY;
+const CORROSION_RESISTANCE: u8 = 150;
+/// How much curing is needed before the concrete sets
+const CURE_TIME: u16 = 1200;
+/// Curing progress per update when dry, next to water the progress is only 1
+const DRY_CURING: u16 = 4;
+
+/// Flows like mud until it cures, then it sets into an immoveable solid.
+#[derive(Clone)]
+pub struct Concrete {
+    flow: SlurryMovement,
+    color: Color,
+    corrodibility: Corrodibility,
+    cure_time: u16,
+}
+
+impl Concrete {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Concrete {
+            flow: SlurryMovement::new(),
+            color: Color::hex(COLOR).similiar(),
+            corrodibility: Corrodibility::Corrodible(CORROSION_RESISTANCE),
+            cure_time: CURE_TIME,
+        })
+    }
+
+    fn is_set(&self) -> bool {
+        self.cure_time == 0
+    }
+}
+
+impl Particle for Concrete {
+    fn get_name(&self) -> &str {
+        "Concrete"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        if !self.is_set() {
+            self.flow.velocity = new_velocity;
+        }
+    }
+
+    fn is_moveable(&self) -> bool {
+        !self.is_set()
+    }
+
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_concrete = self.clone();
+
+        // Dissolve in acid
+        let corroded = Corrodibility::check(&mut new_concrete, &neigborhood);
+        if let PropertyCheckResult::Destroyed = corroded {
+            return ParticleChange::Changed(None);
+        }
+
+        if self.is_set() {
+            return match corroded {
+                PropertyCheckResult::Updated => {
+                    ParticleChange::Changed(Some(Box::new(new_concrete)))
+                }
+                _ => ParticleChange::None,
+            };
+        }
+
+        new_concrete.flow.update(self, &neigborhood);
+
+        // Cure, slower when wet
+        let is_wet = neigborhood.adjacent().any(|opt| {
+            match opt.and_then(|cell| cell.get_particle().as_ref()) {
+                Some(neigh) => neigh.is::<Water>(),
+                None => false,
+            }
+        });
+        let curing = if is_wet { 1 } else { DRY_CURING };
+        new_concrete.cure_time = new_concrete.cure_time.saturating_sub(curing);
+
+        if new_concrete.is_set() {
+            new_concrete.flow.velocity = Velocity::zero();
+            new_concrete.flow.movement = Offset::zero();
+            new_concrete.color = Color::hex(SET_COLOR).similiar();
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_concrete)))
+    }
+}
@@ -0,0 +1,164 @@
+use crate::particles::constants::*;
+use crate::particles::{Particle, Smoke};
+use crate::utility::get_value_around;
+use crate::{AreaEffect, Color, Neighborhood, Offset, Velocity};
+
+use super::properties::PropertyCheckResult;
+use super::{Burnability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xFFD23F;
+/// Colors of the flame from the youngest to the oldest
+const GRADIENT: [u32; 3] = [COLOR, 0xF2771A, FIRE_COLOR];
+const DENSITY: u8 = 10;
+/// Default lifetime in number of updates, the fire dies quickly
+const DEFAULT_LIFETIME: u8 = 25;
+const LIFETIME_OFF: u8 = 10;
+/// One in `FLAME_CHANCE` updates a burning particle emits a flame above it
+const FLAME_CHANCE: u32 = 4;
+/// One in `SMOKE_CHANCE` fires turns into smoke when it dies
+const SMOKE_CHANCE: u32 = 4;
+
+/// Free flame that rises, sets on fire everything it touches and dies quickly.
+#[derive(Clone)]
+pub struct Fire {
+    color: Color,
+    /// The time left is the remaining lifetime
+    burnability: Burnability,
+    lifetime: u8,
+    /// Velocity given by outside forces, the fire rises on its own
+    velocity: Velocity,
+    /// Fractional movement carried over between updates
+    carry: Velocity,
+    movement: Offset,
+}
+
+impl Fire {
+    pub fn new() -> Box<dyn Particle> {
+        let lifetime = get_value_around(DEFAULT_LIFETIME, LIFETIME_OFF);
+
+        Box::new(Fire {
+            color: Color::hex(COLOR).similiar(),
+            burnability: Burnability::IsBurning(lifetime),
+            lifetime,
+            velocity: Velocity::zero(),
+            carry: Velocity::zero(),
+            movement: Offset::zero(),
+        })
+    }
+
+    /// Returns an effect spawning a flame above a burning particle, but only once in a while.
+    pub(super) fn flame_above(neigborhood: &Neighborhood) -> Option<AreaEffect> {
+        let up = neigborhood.gravity().up();
+        if up.is_zero() || fastrand::u32(0..FLAME_CHANCE) != 0 {
+            return None;
+        }
+
+        match neigborhood.on_relative(&up) {
+            Some(cell) if cell.is_empty() => Some(AreaEffect::Spawn {
+                offset: up,
+                particle: Fire::new(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns a flickering color based on how much of the lifetime is left.
+    fn gradient_color(&self) -> Color {
+        let time = match self.burnability {
+            Burnability::IsBurning(time) => time,
+            _ => 0,
+        };
+        let age = 1.0 - time as f32 / self.lifetime.max(1) as f32;
+        let index = ((age * GRADIENT.len() as f32) as usize).min(GRADIENT.len() - 1);
+
+        Color::hex(GRADIENT[index]).similiar()
+    }
+}
+
+impl Particle for Fire {
+    fn get_name(&self) -> &str {
+        "Fire"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Gas
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.velocity = new_velocity;
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        self.burnability
+    }
+
+    fn set_burnability(&mut self, new_burnability: Burnability) -> () {
+        self.burnability = new_burnability;
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_fire = self.clone();
+
+        // Burn out or get put out by water
+        if let PropertyCheckResult::Destroyed =
+            Burnability::check(&mut new_fire, &neigborhood, DEFAULT_LIFETIME, true)
+        {
+            if fastrand::u32(0..SMOKE_CHANCE) == 0 {
+                return ParticleChange::Changed(Some(Smoke::new()));
+            }
+            return ParticleChange::Changed(None);
+        }
+
+        let gravity = neigborhood.gravity();
+        let x_dir = if fastrand::bool() { 1 } else { -1 };
+        // Find new movement, rises and flickers to the sides
+        for_else!(
+            for off in [gravity.up(), gravity.up() + gravity.side(x_dir), gravity.side(x_dir)] => {
+                if let Some(cell) = neigborhood.on_relative(&off) {
+                    match cell.get_particle() {
+                        None => {
+                            new_fire.movement = off;
+                            break;
+                        }
+                        Some(other) => {
+                            if self.can_switch_with(other) {
+                                new_fire.movement = off;
+                                break;
+                            }
+                        }
+                    }
+                }
+            } else {
+                new_fire.movement = Offset::zero();
+            }
+        );
+
+        // Drift with the velocity given by outside forces, slowly losing it
+        new_fire.velocity = new_fire.velocity.slowed_down(AIR_RESISTANCE);
+        new_fire.movement = new_fire.movement + new_fire.velocity.movement(&mut new_fire.carry);
+
+        new_fire.color = new_fire.gradient_color();
+
+        ParticleChange::Changed(Some(Box::new(new_fire)))
+    }
+}
@@ -0,0 +1,247 @@
+use fastrand;
+
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::utility::get_value_around;
+use crate::{Color, Neighborhood, Offset, Velocity};
+
+use super::p_sand::GranularMovement;
+use super::properties::PropertyCheckResult;
+use super::{Burnability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xE8772E;
+const DEAD_COLOR: u32 = 0x8C8C7A;
+/// Same as water, so the fish neither floats nor sinks
+const DENSITY: u8 = 128;
+/// Dead fish sinks in water
+const DEAD_DENSITY: u8 = 140;
+const BURNABILITY_TIME: u8 = 25;
+/// Number of updates a fish survives out of water
+const STRANDED_TIME: u32 = 150;
+const FOCUS_TIME: u32 = 7; // How long can a fish focus on a single direction of swimming
+const FOCUS_TIME_OFFSET: u32 = 7;
+/// How far does a fish see burning particles
+const PERCEPTION: u32 = 2;
+const NUTRITION: u32 = 200;
+const OFFSETS: [Offset; 8] = [
+    Offset { x: 1, y: 0 },
+    Offset { x: -1, y: 0 },
+    Offset { x: 0, y: 1 },
+    Offset { x: 0, y: -1 },
+    Offset { x: 1, y: 1 },
+    Offset { x: -1, y: 1 },
+    Offset { x: 1, y: -1 },
+    Offset { x: -1, y: -1 },
+];
+
+#[derive(Clone)]
+pub struct Fish {
+    /// Used for falling, a swimming fish sets only the movement
+    flow: GranularMovement,
+    color: Color,
+    burnability: Burnability,
+    focus: u32,
+    /// Number of updates the fish has spent out of water
+    stranded: u32,
+    is_alive: bool,
+}
+
+impl Fish {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Fish {
+            flow: GranularMovement::new(),
+            color: Color::hex(COLOR).similiar(),
+            burnability: Burnability::CanBurn,
+            focus: 0,
+            stranded: 0,
+            is_alive: true,
+        })
+    }
+
+    /// Returns true if the fish can live in the particle, which is any liquid that puts out fire (like water or salt water).
+    fn is_habitable(particle: &Box<dyn Particle>) -> bool {
+        *particle.get_matter_type() == MatterType::Liquid
+            && matches!(particle.get_burnability(), Burnability::AntiBurn)
+    }
+
+    /// Returns the direction away from all burning particles the fish can see.
+    fn escape(&self, neigborhood: &Neighborhood) -> Velocity {
+        let mut escape = Velocity::zero();
+        for (off, opt) in neigborhood.iter_relative() {
+            if let Some(neigh) = opt.and_then(|cell| cell.get_particle().as_ref()) {
+                if let Burnability::IsBurning(_) = neigh.get_burnability() {
+                    escape = escape - Velocity::from_offset(&off, 1.0);
+                }
+            }
+        }
+
+        escape
+    }
+
+    /// Falls like sand, used by dead fish and by fish out of water.
+    fn fall(&mut self, neigborhood: &Neighborhood) -> () {
+        let fish = self.clone();
+        self.flow.update(&fish, neigborhood);
+    }
+
+    /// Swims through the water, away from fire if there is any.
+    fn swim(&mut self, neigborhood: &Neighborhood) -> () {
+        self.flow.stop();
+
+        let can_swim_to = |fish: &Fish, off: &Offset| match neigborhood.on_relative(off) {
+            Some(cell) => match cell.get_particle() {
+                Some(other) => fish.can_switch_with(other),
+                None => false,
+            },
+            None => false,
+        };
+
+        let escape = self.escape(neigborhood);
+        if !escape.is_zero() {
+            // Swim in the direction closest to the escape
+            let best = OFFSETS
+                .iter()
+                .filter(|off| can_swim_to(self, off))
+                .map(|off| {
+                    let dir = Velocity::from_offset(off, 1.0).with_magnitude(1.0);
+                    (dir.x * escape.x + dir.y * escape.y, *off)
+                })
+                .max_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            self.flow.movement = match best {
+                Some((_, off)) => off,
+                None => Offset::zero(),
+            };
+            self.focus = 0;
+        } else if self.focus == 0 || !can_swim_to(self, &self.flow.movement) {
+            let mut offsets = OFFSETS;
+            fastrand::shuffle(&mut offsets);
+
+            self.flow.movement = match offsets.iter().find(|off| can_swim_to(self, off)) {
+                Some(off) => *off,
+                None => Offset::zero(),
+            };
+            self.focus = get_value_around(FOCUS_TIME, FOCUS_TIME_OFFSET);
+        } else {
+            self.focus -= 1;
+        }
+    }
+
+    /// Returns this fish dead.
+    fn into_dead(mut self) -> Fish {
+        self.is_alive = false;
+        self.color = Color::hex(DEAD_COLOR).similiar();
+        self
+    }
+}
+
+impl Particle for Fish {
+    fn get_name(&self) -> &str {
+        "Fish"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        if self.is_alive {
+            DENSITY
+        } else {
+            DEAD_DENSITY
+        }
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        self.burnability
+    }
+
+    fn set_burnability(&mut self, new_burnability: Burnability) -> () {
+        self.burnability = new_burnability;
+    }
+
+    fn get_nutrition(&self) -> u32 {
+        if self.is_alive {
+            0
+        } else {
+            NUTRITION
+        }
+    }
+
+    fn can_switch_with(&self, other: &Box<dyn Particle>) -> bool {
+        if self.is_alive {
+            // Living fish can only swim through water
+            Fish::is_habitable(other)
+        } else {
+            self.get_density() > other.get_density()
+        }
+    }
+
+    fn get_neighborhood_radius(&self) -> u32 {
+        PERCEPTION
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_fish = self.clone();
+
+        let res = Burnability::check(&mut new_fish, &neigborhood, BURNABILITY_TIME, true);
+        match res {
+            PropertyCheckResult::Updated => {
+                if let Burnability::IsBurning(_) = new_fish.get_burnability() {
+                    new_fish.color = Color::hex(FIRE_COLOR).similiar();
+                }
+            }
+            PropertyCheckResult::Destroyed => return ParticleChange::Changed(None),
+            PropertyCheckResult::None => {}
+        }
+
+        if !new_fish.is_alive {
+            new_fish.fall(&neigborhood);
+            return ParticleChange::Changed(Some(Box::new(new_fish)));
+        }
+
+        let in_water = OFFSETS.iter().any(|off| {
+            match neigborhood
+                .on_relative(off)
+                .and_then(|cell| cell.get_particle().as_ref())
+            {
+                Some(neigh) => Fish::is_habitable(neigh),
+                None => false,
+            }
+        });
+
+        if in_water {
+            new_fish.stranded = 0;
+            new_fish.swim(&neigborhood);
+        } else {
+            // Stranded on land, flop down and eventually die
+            new_fish.stranded += 1;
+            if new_fish.stranded >= STRANDED_TIME {
+                new_fish = new_fish.into_dead();
+            }
+            new_fish.fall(&neigborhood);
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_fish)))
+    }
+}
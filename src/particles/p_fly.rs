@@ -2,20 +2,32 @@ use crate::particles::constants::*;
 use crate::particles::Particle;
 use crate::utility::get_value_around;
 use crate::Neighborhood;
-use crate::{Color, Offset};
+use crate::{AreaEffect, Color, Offset, Velocity};
 use fastrand;
 
 use super::properties::PropertyCheckResult;
 use super::MatterType;
-use super::{Burnability, ParticleChange};
+use super::{Burnability, Corrodibility, ParticleChange};
 
-const COLOR: u32 = 0x152E02;
+pub(super) const COLOR: u32 = 0x152E02;
 /// Default lifetime in number of updates
 const DEFAULT_LIFETIME: u32 = 1000;
 const LIFETIME_OFF: u32 = 400;
 const BURNABILITY_TIME: u8 = 25;
 const FOCUS_TIME: u32 = 7; // How long can a fly focus on a single direction of movement
 const FOCUS_TIME_OFFSET: u32 = 7;
+/// How far can a fly see in each direction
+const PERCEPTION: u32 = 4;
+/// A fly does not eat when its lifetime is above this
+const MAX_LIFETIME: u32 = 2000;
+/// Lifetime needed to reproduce
+const REPRODUCTION_LIFETIME: u32 = 1600;
+/// Lifetime given up for the offspring
+const REPRODUCTION_COST: u32 = 800;
+/// How much does a fly care about danger compared to food
+const DANGER_WEIGHT: f32 = 4.0;
+/// How much does a swarming fly care about other flies compared to food
+const SWARM_WEIGHT: f32 = 0.5;
 const OFFSETS: [Offset; 9] = [
     Offset { x: 0, y: 0 },
     Offset { x: 1, y: 0 },
@@ -35,6 +47,8 @@ pub struct Fly {
     burnability: Burnability,
     movement: Offset,
     focus: u32,
+    /// Swarming flies are attracted to each other
+    swarming: bool,
 }
 
 impl Fly {
@@ -45,8 +59,59 @@ impl Fly {
             burnability: Burnability::CanBurn,
             movement: Offset::zero(),
             focus: 0,
+            swarming: false,
         })
     }
+
+    /// Creates a fly that is attracted to other flies, its offspring swarm as well.
+    pub fn swarm() -> Box<dyn Particle> {
+        Box::new(Fly {
+            color: Color::hex(COLOR).similiar(),
+            lifetime: get_value_around(DEFAULT_LIFETIME, LIFETIME_OFF),
+            burnability: Burnability::CanBurn,
+            movement: Offset::zero(),
+            focus: 0,
+            swarming: true,
+        })
+    }
+
+    /// Returns the direction the fly wants to go, based on what it sees.
+    /// Attracted by the closest food (and other flies when swarming), repelled by fire, water and acid.
+    fn desire(&self, neigborhood: &Neighborhood) -> Velocity {
+        let mut closest_food: Option<(f32, Offset)> = None;
+        let mut desire = Velocity::zero();
+
+        for (off, opt) in neigborhood.iter_relative() {
+            let neigh = match opt.and_then(|cell| cell.get_particle().as_ref()) {
+                Some(neigh) if !off.is_zero() => neigh,
+                _ => continue,
+            };
+            let distance = Velocity::from_offset(&off, 1.0).magnitude();
+
+            let is_dangerous =
+                matches!(
+                    neigh.get_burnability(),
+                    Burnability::IsBurning(_) | Burnability::AntiBurn
+                ) || matches!(neigh.get_corrodibility(), Corrodibility::Corrosive(_));
+
+            if is_dangerous {
+                desire =
+                    desire - Velocity::from_offset(&off, DANGER_WEIGHT / (distance * distance));
+            } else if neigh.get_nutrition() > 0 {
+                if closest_food.map_or(true, |(closest, _)| distance < closest) {
+                    closest_food = Some((distance, off));
+                }
+            } else if self.swarming && neigh.get_name() == self.get_name() {
+                desire = desire + Velocity::from_offset(&off, SWARM_WEIGHT / (distance * distance));
+            }
+        }
+
+        if let Some((distance, off)) = closest_food {
+            desire = desire + Velocity::from_offset(&off, 1.0 / distance);
+        }
+
+        desire
+    }
 }
 
 impl Particle for Fly {
@@ -66,10 +131,6 @@ impl Particle for Fly {
         MAX_DENSITY
     }
 
-    fn get_velocity(&self) -> f32 {
-        DEFAULT_VELOCITY
-    }
-
     fn is_moveable(&self) -> bool {
         true
     }
@@ -87,6 +148,10 @@ impl Particle for Fly {
         other.get_density() < MAX_GAS_DENSITY
     }
 
+    fn get_neighborhood_radius(&self) -> u32 {
+        PERCEPTION
+    }
+
     fn get_movement(&self) -> Offset {
         self.movement
     }
@@ -101,10 +166,36 @@ impl Particle for Fly {
         let mut new_fly = self.clone();
         new_fly.lifetime -= 1;
 
+        let is_free = |off: &Offset| match neigborhood.on_relative(off) {
+            Some(cell) => match cell.get_particle() {
+                None => true,
+                Some(other) => new_fly.can_switch_with(other),
+            },
+            None => false,
+        };
+
         // Find new movement
+        let desire = new_fly.desire(&neigborhood);
         let on_next_cell = neigborhood.on_relative(&self.movement);
+        if desire.magnitude() > MIN_VELOCITY {
+            // Go in the free direction closest to the desired one, with a bit of randomness
+            let best = OFFSETS[1..]
+                .iter()
+                .filter(|off| is_free(off))
+                .map(|off| {
+                    let dir = Velocity::from_offset(off, 1.0).with_magnitude(1.0);
+                    let score = dir.x * desire.x + dir.y * desire.y + fastrand::f32() * 0.2;
+                    (score, *off)
+                })
+                .max_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            new_fly.movement = match best {
+                Some((_, off)) => off,
+                None => Offset::zero(),
+            };
+            new_fly.focus = 0;
         // !is_none = !(inside AND none) = outisde OR some
-        if new_fly.focus == 0 || !on_next_cell.is_none() {
+        } else if new_fly.focus == 0 || !on_next_cell.is_none() {
             let mut indexes: Vec<usize> = (0..OFFSETS.len()).collect();
             fastrand::shuffle(indexes.as_mut_slice());
             // Loop over offsets indexed by shuffled
@@ -141,11 +232,61 @@ impl Particle for Fly {
                 if let Burnability::IsBurning(_) = new_fly.get_burnability() {
                     new_fly.color = Color::hex(FIRE_COLOR).similiar();
                 }
+            }
+            PropertyCheckResult::Destroyed => return ParticleChange::Changed(None),
+            PropertyCheckResult::None => {}
+        }
+
+        // Eat food right next to the fly
+        if new_fly.lifetime < MAX_LIFETIME {
+            let food = OFFSETS[1..].iter().find_map(|off| {
+                let neigh = neigborhood.on_relative(off)?.get_particle().as_ref()?;
+                (neigh.get_nutrition() > 0).then(|| (*off, neigh.get_nutrition()))
+            });
 
-                ParticleChange::Changed(Some(Box::new(new_fly)))
+            if let Some((offset, nutrition)) = food {
+                // Another fly may eat the same food first, then this one stays hungry
+                let hungry_fly = new_fly.clone();
+                new_fly.lifetime = (new_fly.lifetime + nutrition).min(MAX_LIFETIME);
+                return ParticleChange::ChangedWithEffect(
+                    Some(Box::new(new_fly)),
+                    AreaEffect::ReplaceIf {
+                        offset,
+                        condition: |neigh| neigh.get_nutrition() > 0,
+                        particle: None,
+                        fallback: Some(Box::new(hungry_fly)),
+                    },
+                );
             }
-            PropertyCheckResult::Destroyed => ParticleChange::Changed(None),
-            PropertyCheckResult::None => ParticleChange::Changed(Some(Box::new(new_fly))),
         }
+
+        // Reproduce when well fed, the offspring is placed to a free cell next to the fly
+        if new_fly.lifetime >= REPRODUCTION_LIFETIME {
+            let free = OFFSETS[1..]
+                .iter()
+                .find(|off| match neigborhood.on_relative(off) {
+                    Some(cell) => cell.is_empty(),
+                    None => false,
+                });
+
+            if let Some(offset) = free {
+                new_fly.lifetime -= REPRODUCTION_COST;
+                let offspring = if new_fly.swarming {
+                    Fly::swarm()
+                } else {
+                    Fly::new()
+                };
+
+                return ParticleChange::ChangedWithEffect(
+                    Some(Box::new(new_fly)),
+                    AreaEffect::Spawn {
+                        offset: *offset,
+                        particle: offspring,
+                    },
+                );
+            }
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_fly)))
     }
 }
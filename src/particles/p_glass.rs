@@ -0,0 +1,62 @@
+use crate::particles::constants::*;
+use crate::particles::{Particle, Sand};
+use crate::{Color, Offset};
+
+use super::{MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xC8E6F0;
+/// Glass is see-through
+const ALPHA: u8 = 110;
+const DENSITY: u8 = MAX_DENSITY;
+/// Minimal force of an impact that shatters the glass
+const SHATTER_FORCE: f32 = 1.5;
+
+/// Does not burn nor corrode, but shatters into sand when hit hard enough.
+#[derive(Clone)]
+pub struct Glass {
+    color: Color,
+}
+
+impl Glass {
+    pub fn new() -> Box<dyn Particle> {
+        let Color { r, g, b, .. } = Color::hex(COLOR);
+
+        Box::new(Glass {
+            color: Color::rgba(r, g, b, ALPHA).similiar(),
+        })
+    }
+}
+
+impl Particle for Glass {
+    fn get_name(&self) -> &str {
+        "Glass"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+
+    fn on_impact(&self, force: f32) -> ParticleChange {
+        if force >= SHATTER_FORCE {
+            ParticleChange::Changed(Some(Sand::new()))
+        } else {
+            ParticleChange::None
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::Neighborhood;
+use crate::{AreaEffect, Color, Offset, Velocity};
+
+use super::p_sand::GranularMovement;
+use super::properties::PropertyCheckResult;
+use super::{Burnability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0x2B2B2B;
+/// How long does the gunpowder burn before it explodes
+const FUSE_TIME: u8 = 2;
+const EXPLOSION_RADIUS: u32 = 3;
+const EXPLOSION_FORCE: f32 = 3.0;
+
+/// Explosive powder, falls like sand and explodes shortly after catching fire.
+#[derive(Clone)]
+pub struct Gunpowder {
+    flow: GranularMovement,
+    color: Color,
+    burnability: Burnability,
+}
+
+impl Gunpowder {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Gunpowder {
+            flow: GranularMovement::new(),
+            color: Color::hex(COLOR).similiar(),
+            burnability: Burnability::CanBurn,
+        })
+    }
+}
+
+impl Particle for Gunpowder {
+    fn get_name(&self) -> &str {
+        "Gunpowder"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        MAX_DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        self.burnability
+    }
+
+    fn set_burnability(&mut self, new_burnability: Burnability) -> () {
+        self.burnability = new_burnability;
+    }
+
+    fn is_explosive(&self) -> bool {
+        true
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_gunpowder = self.clone();
+        new_gunpowder.flow.update(self, &neigborhood);
+
+        // Water does not put out the gunpowder, once burning it will explode
+        let res = Burnability::check(&mut new_gunpowder, &neigborhood, FUSE_TIME, false);
+        match res {
+            PropertyCheckResult::Updated => {
+                if let Burnability::IsBurning(_) = new_gunpowder.get_burnability() {
+                    new_gunpowder.color = Color::hex(FIRE_COLOR).similiar();
+                }
+
+                ParticleChange::Changed(Some(Box::new(new_gunpowder)))
+            }
+            PropertyCheckResult::Destroyed => ParticleChange::ChangedWithEffect(
+                None,
+                AreaEffect::Explosion {
+                    radius: EXPLOSION_RADIUS,
+                    force: EXPLOSION_FORCE,
+                },
+            ),
+            PropertyCheckResult::None => ParticleChange::Changed(Some(Box::new(new_gunpowder))),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::particles::constants::*;
+use crate::particles::{Particle, Water};
+use crate::{Color, Neighborhood, Offset};
+
+use super::properties::PropertyCheckResult;
+use super::{Freezability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xA5D8F0;
+const DENSITY: u8 = MAX_DENSITY;
+const MELT_TIME: u8 = 40;
+
+#[derive(Clone)]
+pub struct Ice {
+    color: Color,
+    freezability: Freezability,
+}
+
+impl Ice {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Ice {
+            color: Color::hex(COLOR).similiar(),
+            freezability: Freezability::Frozen(MELT_TIME),
+        })
+    }
+}
+
+impl Particle for Ice {
+    fn get_name(&self) -> &str {
+        "Ice"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_freezability(&self) -> Freezability {
+        self.freezability
+    }
+
+    fn set_freezability(&mut self, new_freezability: Freezability) -> () {
+        self.freezability = new_freezability;
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_ice = self.clone();
+
+        match Freezability::check(&mut new_ice, &neigborhood, MELT_TIME) {
+            PropertyCheckResult::Updated => ParticleChange::Changed(Some(Box::new(new_ice))),
+            // Melted
+            PropertyCheckResult::Destroyed => ParticleChange::Changed(Some(Water::new())),
+            PropertyCheckResult::None => ParticleChange::None,
+        }
+    }
+}
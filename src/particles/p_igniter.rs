@@ -0,0 +1,71 @@
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::{Color, Offset};
+
+use super::{Burnability, Conductivity, MatterType};
+
+pub(super) const COLOR: u32 = 0x6E3B2E;
+const DENSITY: u8 = MAX_DENSITY;
+
+/// Sets its neighbors on fire when charged, does not burn down itself.
+#[derive(Clone)]
+pub struct Igniter {
+    color: Color,
+    is_charged: bool,
+}
+
+impl Igniter {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Igniter {
+            color: Color::hex(COLOR).similiar(),
+            is_charged: false,
+        })
+    }
+}
+
+impl Particle for Igniter {
+    fn get_name(&self) -> &str {
+        "Igniter"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        if self.is_charged {
+            Burnability::IsBurning(u8::MAX)
+        } else {
+            Burnability::None
+        }
+    }
+
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::Consumer
+    }
+
+    fn is_charged(&self) -> bool {
+        self.is_charged
+    }
+
+    fn set_charged(&mut self, charged: bool) -> () {
+        self.is_charged = charged;
+        self.color = Color::hex(if charged { FIRE_COLOR } else { COLOR }).similiar();
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+}
@@ -0,0 +1,64 @@
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::{Color, Offset};
+
+use super::{Conductivity, MatterType};
+
+pub(super) const COLOR: u32 = 0x5A5230;
+const LIT_COLOR: u32 = 0xFFF3A0;
+const DENSITY: u8 = MAX_DENSITY;
+
+/// Lights up when charged.
+#[derive(Clone)]
+pub struct Lamp {
+    color: Color,
+    is_charged: bool,
+}
+
+impl Lamp {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Lamp {
+            color: Color::hex(COLOR).similiar(),
+            is_charged: false,
+        })
+    }
+}
+
+impl Particle for Lamp {
+    fn get_name(&self) -> &str {
+        "Lamp"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::Consumer
+    }
+
+    fn is_charged(&self) -> bool {
+        self.is_charged
+    }
+
+    fn set_charged(&mut self, charged: bool) -> () {
+        self.is_charged = charged;
+        self.color = Color::hex(if charged { LIT_COLOR } else { COLOR }).similiar();
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+}
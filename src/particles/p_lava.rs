@@ -0,0 +1,107 @@
+use crate::particles::constants::*;
+use crate::particles::{Particle, Rock, Salt, SaltWater, Vapor};
+use crate::{AreaEffect, Color, Neighborhood, Offset, Velocity};
+
+use super::p_water::LiquidMovement;
+use super::{Burnability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xCF4B10;
+const DENSITY: u8 = 200;
+/// Number of updates before the lava cools down into rock
+const HEAT: u16 = 600;
+/// How much faster the lava cools down when touching water
+const WATER_COOLING: u16 = 60;
+/// Portion of the side speed of water, lava is much more viscous
+const FLOW: f32 = 0.3;
+
+#[derive(Clone)]
+pub struct Lava {
+    flow: LiquidMovement,
+    color: Color,
+    heat: u16,
+}
+
+impl Lava {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Lava {
+            color: Color::hex(COLOR).similiar(),
+            flow: LiquidMovement::new(),
+            heat: HEAT,
+        })
+    }
+}
+
+impl Particle for Lava {
+    fn get_name(&self) -> &str {
+        "Lava"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Liquid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    /// Lava is always burning, so it sets on fire everything that can burn. It does not burn down, it cools down instead.
+    fn get_burnability(&self) -> Burnability {
+        Burnability::IsBurning(u8::MAX)
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_lava = self.clone();
+
+        // Lava spreads to the sides only slowly, even when pushed by pressure
+        let side_speed = DEFAULT_VELOCITY.max(LiquidMovement::pressure_speed(&neigborhood)) * FLOW;
+        new_lava.flow.update(self, &neigborhood, side_speed);
+
+        // Find water (or anything else that puts out fire) to boil
+        let water = neigborhood.adjacent_relative().find_map(|(off, opt)| {
+            let neigh = opt?.get_particle().as_ref()?;
+            match neigh.get_burnability() {
+                Burnability::AntiBurn => Some((off, neigh.is::<SaltWater>())),
+                _ => None,
+            }
+        });
+
+        // Cool down, much faster when touching water
+        let cooling = if water.is_some() { WATER_COOLING } else { 1 };
+        new_lava.heat = new_lava.heat.saturating_sub(cooling);
+        if new_lava.heat == 0 {
+            return ParticleChange::Changed(Some(Rock::new()));
+        }
+
+        match water {
+            // Salt is left behind from boiled salt water
+            Some((offset, is_salty)) => ParticleChange::ChangedWithEffect(
+                Some(Box::new(new_lava)),
+                AreaEffect::Replace {
+                    offset,
+                    particle: Some(if is_salty { Salt::new() } else { Vapor::new() }),
+                },
+            ),
+            None => ParticleChange::Changed(Some(Box::new(new_lava))),
+        }
+    }
+}
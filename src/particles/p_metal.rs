@@ -0,0 +1,85 @@
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::{Color, Neighborhood, Offset};
+
+use super::properties::PropertyCheckResult;
+use super::{Conductivity, Corrodibility, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0x9EA3A8;
+const CHARGED_COLOR: u32 = 0xC9E6FF;
+const DENSITY: u8 = MAX_DENSITY;
+const CORROSION_RESISTANCE: u8 = 60;
+
+#[derive(Clone)]
+pub struct Metal {
+    color: Color,
+    corrodibility: Corrodibility,
+    is_charged: bool,
+}
+
+impl Metal {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Metal {
+            color: Color::hex(COLOR).similiar(),
+            corrodibility: Corrodibility::Corrodible(CORROSION_RESISTANCE),
+            is_charged: false,
+        })
+    }
+}
+
+impl Particle for Metal {
+    fn get_name(&self) -> &str {
+        "Metal"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::Conductor
+    }
+
+    fn is_charged(&self) -> bool {
+        self.is_charged
+    }
+
+    fn set_charged(&mut self, charged: bool) -> () {
+        self.is_charged = charged;
+        self.color = Color::hex(if charged { CHARGED_COLOR } else { COLOR }).similiar();
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_metal = self.clone();
+
+        match Corrodibility::check(&mut new_metal, &neigborhood) {
+            PropertyCheckResult::Updated => ParticleChange::Changed(Some(Box::new(new_metal))),
+            PropertyCheckResult::Destroyed => ParticleChange::Changed(None),
+            PropertyCheckResult::None => ParticleChange::None,
+        }
+    }
+}
@@ -2,31 +2,32 @@ use crate::particles::constants::*;
 use crate::particles::Particle;
 use crate::utility::get_value_around;
 use crate::Neighborhood;
-use crate::{Color, Offset};
+use crate::{Color, Offset, Velocity};
 
+use super::properties::PropertyCheckResult;
+use super::Corrodibility;
 use super::MatterType;
 use super::ParticleChange;
 
-const COLOR: u32 = 0x91473D;
+pub(super) const COLOR: u32 = 0x91473D;
 const DEFAULT_SIDE_FRICTION: u8 = 16;
 const FRICTION_OFF: u8 = 4;
+const CORROSION_RESISTANCE: u8 = 8;
+const NUTRITION: u32 = 40;
 
 #[derive(Clone)]
 pub struct Mud {
-    velocity: f32,
+    flow: SlurryMovement,
     color: Color,
-    /// Definies how much does the mud particle want to fall to the side. Actually falls when it reaches zero
-    side_friction: u8,
-    movement: Offset,
+    corrodibility: Corrodibility,
 }
 
 impl Mud {
     pub fn new() -> Box<dyn Particle> {
         Box::new(Mud {
-            velocity: DEFAULT_VELOCITY,
+            flow: SlurryMovement::new(),
             color: Color::hex(COLOR).similiar(),
-            side_friction: get_side_friction(),
-            movement: Offset::zero(),
+            corrodibility: Corrodibility::Corrodible(CORROSION_RESISTANCE),
         })
     }
 }
@@ -48,71 +49,122 @@ impl Particle for Mud {
         MAX_DENSITY
     }
 
-    fn get_velocity(&self) -> f32 {
-        self.velocity
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
     }
 
     fn is_moveable(&self) -> bool {
         true
     }
 
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
+    fn get_nutrition(&self) -> u32 {
+        NUTRITION
+    }
+
     fn get_movement(&self) -> Offset {
-        self.movement * self.velocity as i32
+        self.flow.movement
     }
 
     fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
         let mut new_mud = self.clone();
 
+        // Dissolve in acid
+        if let PropertyCheckResult::Destroyed = Corrodibility::check(&mut new_mud, &neigborhood) {
+            return ParticleChange::Changed(None);
+        }
+
+        new_mud.flow.update(self, &neigborhood);
+
+        ParticleChange::Changed(Some(Box::new(new_mud)))
+    }
+}
+
+/// Movement of a thick slurry (like mud) that falls freely but slides to the sides only once in a while.
+/// Shared by all particles that flow like mud.
+#[derive(Clone)]
+pub(super) struct SlurryMovement {
+    pub velocity: Velocity,
+    /// Fractional movement carried over between updates
+    carry: Velocity,
+    /// Definies how much does the particle want to fall to the side. Actually falls when it reaches zero
+    side_friction: u8,
+    pub movement: Offset,
+}
+
+impl SlurryMovement {
+    pub fn new() -> SlurryMovement {
+        SlurryMovement {
+            velocity: Velocity::zero(),
+            carry: Velocity::zero(),
+            side_friction: get_side_friction(),
+            movement: Offset::zero(),
+        }
+    }
+
+    /// Finds the new movement of the `particle` flowing in the neighborhood.
+    pub fn update<T: Particle>(&mut self, particle: &T, neigborhood: &Neighborhood) -> () {
+        let gravity = neigborhood.gravity();
+
         // Empty cell bellow or full but can switch
-        if let Some(cell) = neigborhood.down() {
+        if let Some(cell) = neigborhood.on_relative(&gravity.down()) {
             match cell.get_particle() {
                 None => {
-                    new_mud.movement = Offset::new(0, 1);
-                    new_mud.velocity = MAX_VELOCITY.min(new_mud.velocity + GRAVITY);
+                    self.velocity = gravity.fall(&self.velocity);
+                    self.movement = self.velocity.movement(&mut self.carry);
 
-                    return ParticleChange::Changed(Some(Box::new(new_mud)));
+                    return;
                 }
                 Some(other) => {
-                    if new_mud.can_switch_with(other) {
-                        new_mud.movement = Offset::new(0, 1);
+                    if particle.can_switch_with(other) {
                         // Apply some slowdown as if by friction of switching
-                        new_mud.velocity = DEFAULT_VELOCITY.max(new_mud.velocity - SWITCH_SLOWDOWN);
+                        self.velocity = self.velocity.switching(&gravity.down());
+                        self.movement = self.velocity.movement(&mut self.carry);
 
-                        return ParticleChange::Changed(Some(Box::new(new_mud)));
+                        return;
                     }
                 }
             }
         }
 
         // Cant fall to side yet
-        if new_mud.side_friction > 0 {
-            new_mud.movement = Offset::zero();
-            new_mud.velocity = DEFAULT_VELOCITY;
-            new_mud.side_friction -= 1;
-            return ParticleChange::Changed(Some(Box::new(new_mud)));
+        if self.side_friction > 0 {
+            self.velocity = gravity.rest(&self.velocity);
+            self.movement = self.velocity.movement(&mut self.carry);
+            self.side_friction -= 1;
+            return;
         }
 
         // Find new movement to sides, because side friction is 0
         let rand_x = if fastrand::bool() { 1 } else { -1 };
         for_else!(
-            for off in [Offset::new(-rand_x, 1), Offset::new(rand_x, 1)] => {
+            for off in [gravity.down_side(-rand_x), gravity.down_side(rand_x)] => {
                 if let Some(cell) = neigborhood.on_relative(&off) {
                     match cell.get_particle() {
                         None => {
-                            new_mud.movement = off;
-                            new_mud.velocity = MAX_VELOCITY.min(new_mud.velocity + GRAVITY);
+                            self.velocity = Velocity::from_offset(&off, DEFAULT_VELOCITY.max(self.velocity.magnitude()));
                             // Reset side friction
-                            new_mud.side_friction = get_side_friction();
+                            self.side_friction = get_side_friction();
 
                             break;
                         }
                         Some(other) => {
-                            if self.can_switch_with(other) {
-                                new_mud.movement = off;
+                            if particle.can_switch_with(other) {
                                 // Apply some slowdown as if by friction of switching
-                                new_mud.velocity = DEFAULT_VELOCITY.max(new_mud.velocity - SWITCH_SLOWDOWN);
+                                self.velocity = self.velocity.switching(&off);
                                 // Reset side friction
-                                new_mud.side_friction = get_side_friction();
+                                self.side_friction = get_side_friction();
 
                                 break;
                             }
@@ -120,12 +172,11 @@ impl Particle for Mud {
                     }
                 }
             } else {
-                new_mud.movement = Offset::zero();
-                new_mud.velocity = DEFAULT_VELOCITY;
+                self.velocity = gravity.rest(&self.velocity);
             }
         );
 
-        ParticleChange::Changed(Some(Box::new(new_mud)))
+        self.movement = self.velocity.movement(&mut self.carry);
     }
 }
 
@@ -1,33 +1,30 @@
 use crate::particles::constants::*;
 use crate::particles::Particle;
 use crate::Neighborhood;
-use crate::{Color, Offset};
+use crate::{Color, Offset, Velocity};
 
+use super::p_water::LiquidMovement;
 use super::properties::PropertyCheckResult;
 use super::MatterType;
-use super::{Burnability, ParticleChange, Smoke};
+use super::{Burnability, Fire, ParticleChange, Smoke};
 
-const COLOR: u32 = 0x996E17;
+pub(super) const COLOR: u32 = 0x996E17;
 const DENSITY: u8 = 120;
 const BURNABILITY_TIME: u8 = 100;
 
 #[derive(Clone)]
 pub struct Oil {
-    velocity: f32,
+    flow: LiquidMovement,
     color: Color,
     burnability: Burnability,
-    movement: Offset,
-    x_dir: i32,
 }
 
 impl Oil {
     pub fn new() -> Box<dyn Particle> {
         Box::new(Oil {
-            velocity: DEFAULT_VELOCITY,
             color: Color::hex(COLOR).similiar(),
             burnability: Burnability::CanBurn,
-            movement: Offset::zero(),
-            x_dir: if fastrand::bool() { 1 } else { -1 },
+            flow: LiquidMovement::new(),
         })
     }
 }
@@ -49,8 +46,12 @@ impl Particle for Oil {
         DENSITY
     }
 
-    fn get_velocity(&self) -> f32 {
-        self.velocity
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
     }
 
     fn is_moveable(&self) -> bool {
@@ -66,56 +67,25 @@ impl Particle for Oil {
     }
 
     fn get_movement(&self) -> Offset {
-        self.movement * self.velocity as i32
+        self.flow.movement
     }
 
     fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
         let mut new_oil = self.clone();
 
-        // Check in direction of x_dir for obstacels or out of bounds and move away from them
-        let in_x_dir = neigborhood.on_relative(&Offset::new(new_oil.x_dir, 0));
-        if let Some(cell) = in_x_dir {
-            if let Some(_) = cell.get_particle() {
-                new_oil.x_dir = -new_oil.x_dir;
-            }
-        } else {
-            new_oil.x_dir = -new_oil.x_dir;
-        }
-
-        // Find new movement
-        for_else!(
-            for off in [Offset::new(0, 1), Offset::new(new_oil.x_dir, 0), Offset::new(-new_oil.x_dir, 0)] => {
-                if let Some(cell) = neigborhood.on_relative(&off) {
-                    match cell.get_particle() {
-                        None => {
-                            new_oil.movement = off;
-                            // Check if the movement is down and apply gravity
-                            if off.is_down() {
-                                new_oil.velocity = MAX_VELOCITY.min(new_oil.velocity + GRAVITY);
-                            }
-                            break;
-                        }
-                        Some(other) => {
-                            if self.can_switch_with(other) {
-                                new_oil.movement = off;
-                                // Apply some slowdown as if by friction of switching
-                                new_oil.velocity = DEFAULT_VELOCITY.max(new_oil.velocity - SWITCH_SLOWDOWN);
-                                break;
-                            }
-                        }
-                    }
-                }
-            } else {
-                new_oil.movement = Offset::zero();
-                new_oil.velocity = DEFAULT_VELOCITY;
-            }
-        );
+        let side_speed = DEFAULT_VELOCITY.max(new_oil.flow.velocity.magnitude());
+        new_oil.flow.update(self, &neigborhood, side_speed);
 
         let res = Burnability::check(&mut new_oil, &neigborhood, BURNABILITY_TIME, true);
         match res {
             PropertyCheckResult::Updated => {
                 if let Burnability::IsBurning(_) = new_oil.get_burnability() {
                     new_oil.color = Color::hex(FIRE_COLOR).similiar();
+
+                    // Burning particle emits flames above it
+                    if let Some(flame) = Fire::flame_above(&neigborhood) {
+                        return ParticleChange::ChangedWithEffect(Some(Box::new(new_oil)), flame);
+                    }
                 }
 
                 ParticleChange::Changed(Some(Box::new(new_oil)))
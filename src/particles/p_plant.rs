@@ -0,0 +1,182 @@
+use fastrand;
+
+use crate::particles::constants::*;
+use crate::particles::{Particle, Water};
+use crate::{AreaEffect, Color, Neighborhood, Offset};
+
+use super::properties::PropertyCheckResult;
+use super::{Burnability, Fire, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0x3A9A2E;
+const TIP_COLOR: u32 = 0x7ACC4A;
+const DENSITY: u8 = MAX_DENSITY;
+const BURNABILITY_TIME: u8 = 60;
+/// Number of cells a plant grows from a seed
+const DEFAULT_ENERGY: u8 = 24;
+/// Number of updates between growing by one cell
+const GROW_TIME: u8 = 20;
+/// One in `BRANCH_CHANCE` growths is a new branch to the side
+const BRANCH_CHANCE: u32 = 6;
+/// One in `DRINK_CHANCE` updates the plant drinks a neighboring water
+const DRINK_CHANCE: u32 = 50;
+const NUTRITION: u32 = 150;
+
+#[derive(Clone)]
+pub struct Plant {
+    color: Color,
+    burnability: Burnability,
+    /// How many more cells can grow from this one, only the tip of the plant grows
+    energy: u8,
+    is_tip: bool,
+    grow_time: u8,
+}
+
+impl Plant {
+    pub fn new() -> Box<dyn Particle> {
+        Plant::with_energy(DEFAULT_ENERGY)
+    }
+
+    /// Creates a growing tip of the plant that will grow `energy` more cells.
+    pub fn with_energy(energy: u8) -> Box<dyn Particle> {
+        Box::new(Plant {
+            color: Color::hex(TIP_COLOR).similiar(),
+            burnability: Burnability::CanBurn,
+            energy,
+            is_tip: true,
+            grow_time: GROW_TIME,
+        })
+    }
+
+    /// Returns a stem that no longer grows.
+    fn into_stem(mut self) -> Plant {
+        self.is_tip = false;
+        self.color = Color::hex(COLOR).similiar();
+        self
+    }
+}
+
+impl Particle for Plant {
+    fn get_name(&self) -> &str {
+        "Plant"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        self.burnability
+    }
+
+    fn set_burnability(&mut self, new_burnability: Burnability) -> () {
+        self.burnability = new_burnability;
+    }
+
+    fn get_nutrition(&self) -> u32 {
+        NUTRITION
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_plant = self.clone();
+
+        match Burnability::check(&mut new_plant, &neigborhood, BURNABILITY_TIME, true) {
+            PropertyCheckResult::Destroyed => return ParticleChange::Changed(None),
+            PropertyCheckResult::Updated => {
+                if let Burnability::IsBurning(_) = new_plant.get_burnability() {
+                    new_plant.color = Color::hex(FIRE_COLOR).similiar();
+
+                    // Burning particle emits flames above it
+                    if let Some(flame) = Fire::flame_above(&neigborhood) {
+                        return ParticleChange::ChangedWithEffect(Some(Box::new(new_plant)), flame);
+                    }
+                }
+                return ParticleChange::Changed(Some(Box::new(new_plant)));
+            }
+            PropertyCheckResult::None => {}
+        }
+        // Burning plant does not grow
+        if let Burnability::IsBurning(_) = new_plant.burnability {
+            return ParticleChange::None;
+        }
+
+        let gravity = neigborhood.gravity();
+
+        // Grow against the gravity
+        if new_plant.is_tip && !gravity.is_zero() {
+            if new_plant.energy == 0 {
+                return ParticleChange::Changed(Some(Box::new(new_plant.into_stem())));
+            }
+
+            new_plant.grow_time -= 1;
+            if new_plant.grow_time > 0 {
+                return ParticleChange::Changed(Some(Box::new(new_plant)));
+            }
+            new_plant.grow_time = GROW_TIME;
+
+            let rand_x = if fastrand::bool() { 1 } else { -1 };
+            let up_side = gravity.up() + gravity.side(rand_x);
+            let branch = fastrand::u32(0..BRANCH_CHANCE) == 0;
+
+            let free = |off: &Offset| match neigborhood.on_relative(off) {
+                Some(cell) => cell.is_empty(),
+                None => false,
+            };
+
+            // Branch to the side and keep growing, or grow up (to the side if blocked) and become a stem
+            let (offset, new_plant) = if branch && free(&up_side) {
+                new_plant.energy -= 1;
+                (up_side, new_plant)
+            } else {
+                match [gravity.up(), up_side].into_iter().find(|off| free(off)) {
+                    Some(offset) => (offset, new_plant.into_stem()),
+                    // Blocked, stop growing
+                    None => return ParticleChange::Changed(Some(Box::new(new_plant.into_stem()))),
+                }
+            };
+
+            return ParticleChange::ChangedWithEffect(
+                Some(Box::new(new_plant)),
+                AreaEffect::Spawn {
+                    offset,
+                    particle: Plant::with_energy(self.energy - 1),
+                },
+            );
+        }
+
+        // Roots drink water around them
+        if fastrand::u32(0..DRINK_CHANCE) == 0 {
+            let water = neigborhood.adjacent_relative().find_map(|(off, opt)| {
+                let neigh = opt?.get_particle().as_ref()?;
+                neigh.is::<Water>().then_some(off)
+            });
+
+            if let Some(offset) = water {
+                return ParticleChange::ChangedWithEffect(
+                    Some(Box::new(new_plant)),
+                    AreaEffect::Replace {
+                        offset,
+                        particle: None,
+                    },
+                );
+            }
+        }
+
+        ParticleChange::None
+    }
+}
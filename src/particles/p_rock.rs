@@ -1,21 +1,25 @@
 use crate::particles::constants::*;
 use crate::particles::Particle;
-use crate::{Color, Offset};
+use crate::{Color, Neighborhood, Offset};
 
-use super::MatterType;
+use super::properties::PropertyCheckResult;
+use super::{Corrodibility, MatterType, ParticleChange};
 
-const COLOR: u32 = 0x474747;
+pub(super) const COLOR: u32 = 0x474747;
 const DENSITY: u8 = MAX_DENSITY;
+const CORROSION_RESISTANCE: u8 = 200;
 
 #[derive(Clone)]
 pub struct Rock {
     color: Color,
+    corrodibility: Corrodibility,
 }
 
 impl Rock {
     pub fn new() -> Box<dyn Particle> {
         Box::new(Rock {
             color: Color::hex(COLOR).similiar(),
+            corrodibility: Corrodibility::Corrodible(CORROSION_RESISTANCE),
         })
     }
 }
@@ -41,7 +45,29 @@ impl Particle for Rock {
         false
     }
 
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
     fn get_movement(&self) -> Offset {
         Offset::zero()
     }
+
+    fn blocks_explosions(&self) -> bool {
+        true
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_rock = self.clone();
+
+        match Corrodibility::check(&mut new_rock, &neigborhood) {
+            PropertyCheckResult::Updated => ParticleChange::Changed(Some(Box::new(new_rock))),
+            PropertyCheckResult::Destroyed => ParticleChange::Changed(None),
+            PropertyCheckResult::None => ParticleChange::None,
+        }
+    }
 }
@@ -0,0 +1,82 @@
+use crate::particles::{Particle, SaltWater, Water};
+use crate::{AreaEffect, Color, Neighborhood, Offset, Velocity};
+
+use super::p_sand::GranularMovement;
+use super::{MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xDCD6CF;
+const DENSITY: u8 = 200;
+
+#[derive(Clone)]
+pub struct Salt {
+    flow: GranularMovement,
+    color: Color,
+}
+
+impl Salt {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Salt {
+            flow: GranularMovement::new(),
+            color: Color::hex(COLOR).similiar(),
+        })
+    }
+}
+
+impl Particle for Salt {
+    fn get_name(&self) -> &str {
+        "Salt"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        // Dissolve in fresh water, turning it into salt water
+        let water = neigborhood.adjacent_relative().find_map(|(off, opt)| {
+            let neigh = opt?.get_particle().as_ref()?;
+            neigh.is::<Water>().then_some(off)
+        });
+        if let Some(offset) = water {
+            // Another salt may dissolve in the same water first, then this one stays
+            return ParticleChange::ChangedWithEffect(
+                None,
+                AreaEffect::ReplaceIf {
+                    offset,
+                    condition: |neigh| neigh.is::<Water>(),
+                    particle: Some(SaltWater::new()),
+                    fallback: Some(Box::new(self.clone())),
+                },
+            );
+        }
+
+        let mut new_salt = self.clone();
+        new_salt.flow.update(self, &neigborhood);
+
+        ParticleChange::Changed(Some(Box::new(new_salt)))
+    }
+}
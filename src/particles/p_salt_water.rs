@@ -0,0 +1,124 @@
+use crate::particles::Particle;
+use crate::particles::{constants::*, Ice, Salt, Vapor};
+use crate::{AreaEffect, Color, Neighborhood, Offset, Velocity};
+
+use super::p_water::LiquidMovement;
+use super::properties::PropertyCheckResult;
+use super::{Burnability, Conductivity, Freezability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0x3F7FC0;
+/// Heavier than water, so it sinks below it
+const DENSITY: u8 = 140;
+/// Salt water takes much longer to freeze than water
+const FREEZE_TIME: u8 = 200;
+
+#[derive(Clone)]
+pub struct SaltWater {
+    flow: LiquidMovement,
+    color: Color,
+    freezability: Freezability,
+}
+
+impl SaltWater {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(SaltWater {
+            color: Color::hex(COLOR).similiar(),
+            flow: LiquidMovement::new(),
+            freezability: Freezability::CanFreeze(FREEZE_TIME),
+        })
+    }
+}
+
+impl Particle for SaltWater {
+    fn get_name(&self) -> &str {
+        "Salt Water"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Liquid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        Burnability::AntiBurn
+    }
+
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::Shorting
+    }
+
+    fn get_freezability(&self) -> Freezability {
+        self.freezability
+    }
+
+    fn set_freezability(&mut self, new_freezability: Freezability) -> () {
+        self.freezability = new_freezability;
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_water = self.clone();
+        let gravity = neigborhood.gravity();
+
+        let side_speed = DEFAULT_VELOCITY
+            .max(new_water.flow.velocity.magnitude())
+            .max(LiquidMovement::pressure_speed(&neigborhood));
+        new_water.flow.update(self, &neigborhood, side_speed);
+
+        // Check number of neighbors that are IsBurning and AntiBurn
+        let mut count = 0;
+        for opt in neigborhood.adjacent() {
+            if let Some(cell) = opt {
+                if let Some(neigh) = cell.get_particle() {
+                    match neigh.get_burnability() {
+                        Burnability::IsBurning(_) => count += 1,
+                        Burnability::AntiBurn => count -= 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Evaporate, the salt crystallizes and the vapor rises above it (if there is space)
+        if count > 0 {
+            return ParticleChange::ChangedWithEffect(
+                Some(Salt::new()),
+                AreaEffect::Spawn {
+                    offset: gravity.up(),
+                    particle: Vapor::new(),
+                },
+            );
+        }
+
+        // Freeze if next to ice for long enough
+        if let PropertyCheckResult::Destroyed =
+            Freezability::check(&mut new_water, &neigborhood, FREEZE_TIME)
+        {
+            return ParticleChange::Changed(Some(Ice::new()));
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_water)))
+    }
+}
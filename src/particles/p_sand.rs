@@ -1,28 +1,36 @@
 use fastrand;
 
 use crate::particles::constants::*;
-use crate::particles::Particle;
+use crate::particles::{Glass, Particle};
 use crate::Neighborhood;
-use crate::{Color, Offset};
+use crate::{Color, Offset, Velocity};
 
+use super::properties::PropertyCheckResult;
 use super::MatterType;
 use super::ParticleChange;
+use super::{Burnability, Corrodibility};
 
-const COLOR: u32 = 0xE0E02D;
+pub(super) const COLOR: u32 = 0xE0E02D;
+const CORROSION_RESISTANCE: u8 = 12;
+/// Number of updates next to fire needed to melt the sand into glass
+const GLASS_HEAT: u8 = 80;
 
 #[derive(Clone)]
 pub struct Sand {
-    velocity: f32,
+    flow: GranularMovement,
     color: Color,
-    movement: Offset,
+    corrodibility: Corrodibility,
+    /// Increases when next to fire and slowly decreases when not
+    heat: u8,
 }
 
 impl Sand {
     pub fn new() -> Box<dyn Particle> {
         Box::new(Sand {
-            velocity: DEFAULT_VELOCITY,
+            flow: GranularMovement::new(),
             color: Color::hex(COLOR).similiar(),
-            movement: Offset::new(0, 1),
+            corrodibility: Corrodibility::Corrodible(CORROSION_RESISTANCE),
+            heat: 0,
         })
     }
 }
@@ -44,52 +52,121 @@ impl Particle for Sand {
         MAX_DENSITY
     }
 
-    fn get_velocity(&self) -> f32 {
-        self.velocity
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
     }
 
     fn is_moveable(&self) -> bool {
         true
     }
 
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
     fn get_movement(&self) -> Offset {
-        self.movement * self.velocity as i32
+        self.flow.movement
     }
 
     fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
         let mut new_sand = self.clone();
 
+        // Dissolve in acid
+        if let PropertyCheckResult::Destroyed = Corrodibility::check(&mut new_sand, &neigborhood) {
+            return ParticleChange::Changed(None);
+        }
+
+        // Melt into glass under sustained heat
+        let is_heated = neigborhood.adjacent().any(|opt| {
+            match opt.and_then(|cell| cell.get_particle().as_ref()) {
+                Some(neigh) => matches!(neigh.get_burnability(), Burnability::IsBurning(_)),
+                None => false,
+            }
+        });
+        if is_heated {
+            new_sand.heat += 1;
+            if new_sand.heat >= GLASS_HEAT {
+                return ParticleChange::Changed(Some(Glass::new()));
+            }
+        } else {
+            new_sand.heat = new_sand.heat.saturating_sub(1);
+        }
+
+        new_sand.flow.update(self, &neigborhood);
+
+        ParticleChange::Changed(Some(Box::new(new_sand)))
+    }
+}
+
+/// Movement of a granular solid (like sand) that falls freely and slides down to the sides when it can not fall.
+/// Shared by all particles that pile up like sand.
+#[derive(Clone)]
+pub(super) struct GranularMovement {
+    pub velocity: Velocity,
+    /// Fractional movement carried over between updates
+    carry: Velocity,
+    pub movement: Offset,
+}
+
+impl GranularMovement {
+    pub fn new() -> GranularMovement {
+        GranularMovement {
+            velocity: Velocity::zero(),
+            carry: Velocity::zero(),
+            movement: Offset::zero(),
+        }
+    }
+
+    /// Stops falling and drops the carried movement, used when the particle moves on its own.
+    pub fn stop(&mut self) -> () {
+        self.velocity = Velocity::zero();
+        self.carry = Velocity::zero();
+    }
+
+    /// Finds the new movement of the `particle` falling in the neighborhood.
+    pub fn update<T: Particle>(&mut self, particle: &T, neigborhood: &Neighborhood) -> () {
+        let gravity = neigborhood.gravity();
+
         // Find new movement
         let rand_x = if fastrand::bool() { 1 } else { -1 };
         for_else!(
-            for off in [Offset::new(0, 1), Offset::new(-rand_x, 1), Offset::new(rand_x, 1)] => {
+            for off in [gravity.down(), gravity.down_side(-rand_x), gravity.down_side(rand_x)] => {
                 if let Some(cell) = neigborhood.on_relative(&off) {
                     match cell.get_particle() {
                         None => {
-                            new_sand.movement = off;
-                            // Check if the movement is down and apply gravity
-                            if off.is_down() {
-                                new_sand.velocity = MAX_VELOCITY.min(new_sand.velocity + GRAVITY);
+                            if gravity.is_down(&off) {
+                                // Falling freely, keep the momentum and apply gravity
+                                self.velocity = gravity.fall(&self.velocity);
+                            } else {
+                                // Sliding down to the side
+                                self.velocity = Velocity::from_offset(&off, DEFAULT_VELOCITY.max(self.velocity.magnitude()));
                             }
 
                             break;
                         }
                         Some(other) => {
-                            if self.can_switch_with(other) {
-                                new_sand.movement = off;
+                            if particle.can_switch_with(other) {
                                 // Apply some slowdown as if by friction of switching
-                                new_sand.velocity = DEFAULT_VELOCITY.max(new_sand.velocity - SWITCH_SLOWDOWN);
+                                self.velocity = self.velocity.switching(&off);
                                 break;
                             }
                         }
                     }
                 }
             } else {
-                new_sand.movement = Offset::zero();
-                new_sand.velocity = DEFAULT_VELOCITY;
+                // Resting, only the momentum to the sides is left
+                self.velocity = gravity.rest(&self.velocity);
             }
         );
 
-        ParticleChange::Changed(Some(Box::new(new_sand)))
+        self.movement = self.velocity.movement(&mut self.carry);
     }
 }
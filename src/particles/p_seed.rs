@@ -0,0 +1,119 @@
+use crate::particles::{Mud, Particle, Plant, Water};
+use crate::{AreaEffect, Color, Neighborhood, Offset, Velocity};
+
+use super::p_sand::GranularMovement;
+use super::properties::PropertyCheckResult;
+use super::{Burnability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0x8B6B3D;
+const DENSITY: u8 = 150;
+const BURNABILITY_TIME: u8 = 20;
+const NUTRITION: u32 = 100;
+
+#[derive(Clone)]
+pub struct Seed {
+    flow: GranularMovement,
+    color: Color,
+    burnability: Burnability,
+}
+
+impl Seed {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Seed {
+            flow: GranularMovement::new(),
+            color: Color::hex(COLOR).similiar(),
+            burnability: Burnability::CanBurn,
+        })
+    }
+}
+
+impl Particle for Seed {
+    fn get_name(&self) -> &str {
+        "Seed"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        self.burnability
+    }
+
+    fn set_burnability(&mut self, new_burnability: Burnability) -> () {
+        self.burnability = new_burnability;
+    }
+
+    fn get_nutrition(&self) -> u32 {
+        NUTRITION
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_seed = self.clone();
+
+        if let PropertyCheckResult::Destroyed =
+            Burnability::check(&mut new_seed, &neigborhood, BURNABILITY_TIME, true)
+        {
+            return ParticleChange::Changed(None);
+        }
+
+        new_seed.flow.update(self, &neigborhood);
+
+        // Germinate when resting next to water or mud, the water is consumed
+        if new_seed.flow.movement.is_zero() {
+            let wet = neigborhood.adjacent_relative().find_map(|(off, opt)| {
+                let neigh = opt?.get_particle().as_ref()?;
+                if neigh.is::<Water>() {
+                    Some((off, true))
+                } else if neigh.is::<Mud>() {
+                    Some((off, false))
+                } else {
+                    None
+                }
+            });
+
+            match wet {
+                Some((offset, true)) => {
+                    // Another seed may drink the same water first, then this one keeps waiting
+                    return ParticleChange::ChangedWithEffect(
+                        Some(Plant::new()),
+                        AreaEffect::ReplaceIf {
+                            offset,
+                            condition: |neigh| neigh.is::<Water>(),
+                            particle: None,
+                            fallback: Some(Box::new(new_seed)),
+                        },
+                    );
+                }
+                Some((_, false)) => return ParticleChange::Changed(Some(Plant::new())),
+                None => {}
+            }
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_seed)))
+    }
+}
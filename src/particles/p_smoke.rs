@@ -1,10 +1,11 @@
+use crate::particles::constants::AIR_RESISTANCE;
 use crate::particles::Particle;
 use crate::utility::get_value_around;
-use crate::{Color, Neighborhood, Offset};
+use crate::{Color, Neighborhood, Offset, Velocity};
 
 use super::{MatterType, ParticleChange};
 
-const COLOR: u32 = 0xB1B6BD;
+pub(super) const COLOR: u32 = 0xB1B6BD;
 const DENSITY: u8 = 20;
 /// Default lifetime in number of updates
 const DEFAULT_LIFETIME: u32 = 750;
@@ -14,6 +15,10 @@ const LIFETIME_OFF: u32 = 300;
 pub struct Smoke {
     color: Color,
     lifetime: u32,
+    /// Velocity given by outside forces, the smoke rises on its own
+    velocity: Velocity,
+    /// Fractional movement carried over between updates
+    carry: Velocity,
     movement: Offset,
 }
 
@@ -22,6 +27,8 @@ impl Smoke {
         Box::new(Smoke {
             color: Color::hex(COLOR).similiar(),
             lifetime: get_value_around(DEFAULT_LIFETIME, LIFETIME_OFF),
+            velocity: Velocity::zero(),
+            carry: Velocity::zero(),
             movement: Offset::zero(),
         })
     }
@@ -48,6 +55,14 @@ impl Particle for Smoke {
         true
     }
 
+    fn get_velocity(&self) -> Velocity {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.velocity = new_velocity;
+    }
+
     fn get_movement(&self) -> Offset {
         self.movement
     }
@@ -62,10 +77,11 @@ impl Particle for Smoke {
         let mut new_smoke = self.clone();
         new_smoke.lifetime -= 1;
 
+        let gravity = neigborhood.gravity();
         let x_dir = if fastrand::bool() { 1 } else { -1 };
         // Find new movement
         for_else!(
-            for off in [Offset::new(0, -1), Offset::new(x_dir, 0), Offset::new(-x_dir, 0)] => {
+            for off in [gravity.up(), gravity.side(x_dir), gravity.side(-x_dir)] => {
                 if let Some(cell) = neigborhood.on_relative(&off) {
                     match cell.get_particle() {
                         None => {
@@ -85,6 +101,10 @@ impl Particle for Smoke {
             }
         );
 
+        // Drift with the velocity given by outside forces, slowly losing it
+        new_smoke.velocity = new_smoke.velocity.slowed_down(AIR_RESISTANCE);
+        new_smoke.movement = new_smoke.movement + new_smoke.velocity.movement(&mut new_smoke.carry);
+
         ParticleChange::Changed(Some(Box::new(new_smoke)))
     }
 }
@@ -0,0 +1,115 @@
+use crate::particles::{Ice, Particle, Water};
+use crate::{Color, Neighborhood, Offset, Velocity};
+
+use super::p_sand::GranularMovement;
+use super::properties::PropertyCheckResult;
+use super::{Freezability, MatterType, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xF2F5F7;
+const DENSITY: u8 = 90;
+const MELT_TIME: u8 = 10;
+/// Number of particles above the snow needed to compact it
+const COMPACT_WEIGHT: u32 = 3;
+/// Number of updates the snow has to be under weight before it turns into ice
+const COMPACT_TIME: u16 = 300;
+
+#[derive(Clone)]
+pub struct Snow {
+    flow: GranularMovement,
+    color: Color,
+    freezability: Freezability,
+    compact_time: u16,
+}
+
+impl Snow {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Snow {
+            flow: GranularMovement::new(),
+            color: Color::hex(COLOR).similiar(),
+            freezability: Freezability::Frozen(MELT_TIME),
+            compact_time: COMPACT_TIME,
+        })
+    }
+}
+
+impl Particle for Snow {
+    fn get_name(&self) -> &str {
+        "Snow"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_freezability(&self) -> Freezability {
+        self.freezability
+    }
+
+    fn set_freezability(&mut self, new_freezability: Freezability) -> () {
+        self.freezability = new_freezability;
+    }
+
+    /// Snow looks further up to feel the weight of the particles above it.
+    /// Melting still reacts only to the adjacent cells.
+    fn get_neighborhood_radius(&self) -> u32 {
+        COMPACT_WEIGHT
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.flow.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_snow = self.clone();
+        new_snow.flow.update(self, &neigborhood);
+
+        if let PropertyCheckResult::Destroyed =
+            Freezability::check(&mut new_snow, &neigborhood, MELT_TIME)
+        {
+            // Melted
+            return ParticleChange::Changed(Some(Water::new()));
+        }
+
+        // Compact into ice if resting under enough weight
+        let gravity = neigborhood.gravity();
+        let under_weight = !gravity.is_zero()
+            && new_snow.flow.movement.is_zero()
+            && (1..=COMPACT_WEIGHT as i32).all(|i| {
+                match neigborhood.on_relative(&(gravity.up() * i)) {
+                    Some(cell) => !cell.is_empty(),
+                    None => false,
+                }
+            });
+
+        if under_weight {
+            new_snow.compact_time = new_snow.compact_time.saturating_sub(1);
+            if new_snow.compact_time == 0 {
+                return ParticleChange::Changed(Some(Ice::new()));
+            }
+        } else {
+            new_snow.compact_time = COMPACT_TIME;
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_snow)))
+    }
+}
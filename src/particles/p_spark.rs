@@ -9,15 +9,10 @@ use super::properties::PropertyCheckResult;
 use super::MatterType;
 use super::{Burnability, ParticleChange};
 
-const COLOR: u32 = FIRE_COLOR;
+pub(super) const COLOR: u32 = FIRE_COLOR;
 /// Default lifetime in number of updates
 const DEFAULT_LIFETIME: u8 = 200;
 const LIFETIME_OFF: u8 = 50;
-const OFFSETS: [Offset; 3] = [
-    Offset { x: 1, y: 0 },
-    Offset { x: -1, y: 0 },
-    Offset { x: 0, y: 1 },
-];
 
 #[derive(Clone)]
 pub struct Spark {
@@ -74,13 +69,12 @@ impl Particle for Spark {
         let mut new_spark = self.clone();
 
         // Find new movement
-        // Shuffle indexes
-        let mut indexes: Vec<usize> = (0..OFFSETS.len()).collect();
-        fastrand::shuffle(indexes.as_mut_slice());
-        // Loop over offsets indexed by shuffled
+        // Sparks move to the sides or fall down, in random order
+        let gravity = neigborhood.gravity();
+        let mut offsets = [gravity.right(), gravity.left(), gravity.down()];
+        fastrand::shuffle(offsets.as_mut_slice());
         for_else!(
-            for index in indexes => {
-                let off = OFFSETS[index];
+            for off in offsets => {
                 if let Some(cell) = neigborhood.on_relative(&off) {
                     match cell.get_particle() {
                         None => {
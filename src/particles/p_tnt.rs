@@ -0,0 +1,94 @@
+use crate::particles::constants::*;
+use crate::particles::Particle;
+use crate::Neighborhood;
+use crate::{AreaEffect, Color, Offset};
+
+use super::particle::MatterType;
+use super::properties::PropertyCheckResult;
+use super::{Burnability, ParticleChange};
+
+pub(super) const COLOR: u32 = 0xC8102E;
+const DENSITY: u8 = MAX_DENSITY;
+/// How long does the TNT burn before it explodes
+const FUSE_TIME: u8 = 30;
+const EXPLOSION_RADIUS: u32 = 6;
+const EXPLOSION_FORCE: f32 = MAX_VELOCITY;
+
+/// Static explosive, explodes with a big blast after a short fuse once it catches fire.
+#[derive(Clone)]
+pub struct Tnt {
+    color: Color,
+    burnability: Burnability,
+}
+
+impl Tnt {
+    pub fn new() -> Box<dyn Particle> {
+        Box::new(Tnt {
+            color: Color::hex(COLOR).similiar(),
+            burnability: Burnability::CanBurn,
+        })
+    }
+}
+
+impl Particle for Tnt {
+    fn get_name(&self) -> &str {
+        "TNT"
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &MatterType::Solid
+    }
+
+    fn get_density(&self) -> u8 {
+        DENSITY
+    }
+
+    fn is_moveable(&self) -> bool {
+        false
+    }
+
+    fn get_burnability(&self) -> Burnability {
+        self.burnability
+    }
+
+    fn set_burnability(&mut self, new_burnability: Burnability) -> () {
+        self.burnability = new_burnability;
+    }
+
+    fn is_explosive(&self) -> bool {
+        true
+    }
+
+    fn get_movement(&self) -> Offset {
+        Offset::zero()
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_tnt = self.clone();
+
+        // Water does not put out the fuse, once burning it will explode
+        let res = Burnability::check(&mut new_tnt, &neigborhood, FUSE_TIME, false);
+
+        match res {
+            PropertyCheckResult::Updated => {
+                if let Burnability::IsBurning(_) = new_tnt.get_burnability() {
+                    new_tnt.color = Color::hex(FIRE_COLOR).similiar();
+                }
+
+                ParticleChange::Changed(Some(Box::new(new_tnt)))
+            }
+            PropertyCheckResult::Destroyed => ParticleChange::ChangedWithEffect(
+                None,
+                AreaEffect::Explosion {
+                    radius: EXPLOSION_RADIUS,
+                    force: EXPLOSION_FORCE,
+                },
+            ),
+            PropertyCheckResult::None => ParticleChange::None,
+        }
+    }
+}
@@ -1,30 +1,30 @@
 use fastrand;
 
 use crate::particles::Particle;
-use crate::particles::{constants::*, Vapor};
-use crate::{Color, Neighborhood, Offset};
+use crate::particles::{constants::*, Ice, Vapor};
+use crate::{Color, Neighborhood, Offset, Velocity};
 
 // use super::{Burnability, Neighborhood, ParticleChange, Vapor};
-use super::{Burnability, MatterType, ParticleChange};
+use super::properties::PropertyCheckResult;
+use super::{Burnability, Conductivity, Freezability, MatterType, ParticleChange};
 
-const COLOR: u32 = 0x326ECF;
+pub(super) const COLOR: u32 = 0x326ECF;
 const DENSITY: u8 = 128;
+const FREEZE_TIME: u8 = 60;
 
 #[derive(Clone)]
 pub struct Water {
-    velocity: f32,
+    flow: LiquidMovement,
     color: Color,
-    movement: Offset,
-    x_dir: i32, // Used to keep water keeping in one side direction until it can no longer - helps with spreading
+    freezability: Freezability,
 }
 
 impl Water {
     pub fn new() -> Box<dyn Particle> {
         Box::new(Water {
-            velocity: DEFAULT_VELOCITY,
             color: Color::hex(COLOR).similiar(),
-            movement: Offset::new(0, 1),
-            x_dir: if fastrand::bool() { 1 } else { -1 }, // Start with a random x_dir
+            flow: LiquidMovement::new(),
+            freezability: Freezability::CanFreeze(FREEZE_TIME),
         })
     }
 }
@@ -46,8 +46,12 @@ impl Particle for Water {
         DENSITY
     }
 
-    fn get_velocity(&self) -> f32 {
-        self.velocity
+    fn get_velocity(&self) -> Velocity {
+        self.flow.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.flow.velocity = new_velocity;
     }
 
     fn is_moveable(&self) -> bool {
@@ -58,81 +62,142 @@ impl Particle for Water {
         Burnability::AntiBurn
     }
 
+    /// Water conducts the charge but shorts the circuit.
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::Shorting
+    }
+
+    fn get_freezability(&self) -> Freezability {
+        self.freezability
+    }
+
+    fn set_freezability(&mut self, new_freezability: Freezability) -> () {
+        self.freezability = new_freezability;
+    }
+
     fn get_movement(&self) -> Offset {
-        self.movement * self.velocity as i32
+        self.flow.movement
     }
 
     fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
         let mut new_water = self.clone();
 
-        // Check in direction of x_dir for obstacels or out of bounds and move away from them
-        let in_x_dir = neigborhood.on_relative(&Offset::new(new_water.x_dir, 0));
-        if let Some(cell) = in_x_dir {
-            if let Some(_) = cell.get_particle() {
-                new_water.x_dir = -new_water.x_dir;
+        // Speed at which the water spreads to the sides, pressure pushes it faster
+        let side_speed = DEFAULT_VELOCITY
+            .max(new_water.flow.velocity.magnitude())
+            .max(LiquidMovement::pressure_speed(&neigborhood));
+        new_water.flow.update(self, &neigborhood, side_speed);
+
+        // Check number of neighbors that are IsBurning and AntiBurn
+        let mut count = 0;
+        for opt in neigborhood.adjacent() {
+            if let Some(cell) = opt {
+                if let Some(neigh) = cell.get_particle() {
+                    match neigh.get_burnability() {
+                        Burnability::IsBurning(_) => count += 1,
+                        Burnability::AntiBurn => count -= 1,
+                        _ => {}
+                    }
+                }
             }
-        } else {
-            new_water.x_dir = -new_water.x_dir;
         }
 
+        if count > 0 {
+            return ParticleChange::Changed(Some(Vapor::new()));
+        }
+
+        // Freeze if next to ice for long enough
+        if let PropertyCheckResult::Destroyed =
+            Freezability::check(&mut new_water, &neigborhood, FREEZE_TIME)
+        {
+            return ParticleChange::Changed(Some(Ice::new()));
+        }
+
+        ParticleChange::Changed(Some(Box::new(new_water)))
+    }
+}
+
+/// Movement of a liquid that falls freely and spreads to the sides when it can not fall.
+/// Keeps flowing to one side until it can no longer, which helps with spreading.
+/// Shared by all liquid particles.
+#[derive(Clone)]
+pub(super) struct LiquidMovement {
+    pub velocity: Velocity,
+    /// Fractional movement carried over between updates
+    carry: Velocity,
+    /// Side the liquid is flowing to, 1 or -1
+    x_dir: i32,
+    pub movement: Offset,
+}
+
+impl LiquidMovement {
+    pub fn new() -> LiquidMovement {
+        LiquidMovement {
+            velocity: Velocity::zero(),
+            carry: Velocity::zero(),
+            x_dir: if fastrand::bool() { 1 } else { -1 }, // Start with a random x_dir
+            movement: Offset::zero(),
+        }
+    }
+
+    /// Returns the speed to the sides caused by the pressure in the center of the neighborhood, 0 without pressure.
+    pub fn pressure_speed(neigborhood: &Neighborhood) -> f32 {
         let pressure = match neigborhood.center() {
             Some(cell) => cell.get_pressure(),
             None => CELL_DEFAULT_PRESSURE,
         };
 
-        if pressure != CELL_DEFAULT_PRESSURE {
-            let velocity = (pressure as f32 / 5.0).min(MAX_VELOCITY);
-            new_water.velocity = new_water.velocity.max(velocity);
+        (pressure as f32 / 5.0).min(MAX_VELOCITY)
+    }
+
+    /// Finds the new movement of the `particle` flowing in the neighborhood.
+    /// The liquid spreads to the sides with the `side_speed`.
+    pub fn update<T: Particle>(
+        &mut self,
+        particle: &T,
+        neigborhood: &Neighborhood,
+        side_speed: f32,
+    ) -> () {
+        let gravity = neigborhood.gravity();
+
+        // Check in direction of x_dir for obstacels or out of bounds and move away from them
+        let in_x_dir = neigborhood.on_relative(&gravity.side(self.x_dir));
+        if let Some(cell) = in_x_dir {
+            if let Some(_) = cell.get_particle() {
+                self.x_dir = -self.x_dir;
+            }
         } else {
+            self.x_dir = -self.x_dir;
         }
 
         // Find new movement
         for_else!(
-            for off in [Offset::new(0, 1), Offset::new(new_water.x_dir, 0), Offset::new(-new_water.x_dir, 0)] => {
+            for off in [gravity.down(), gravity.side(self.x_dir), gravity.side(-self.x_dir)] => {
                 if let Some(cell) = neigborhood.on_relative(&off) {
                     match cell.get_particle() {
                         None => {
-                            new_water.movement = off;
-                            // Check if the movement is down and apply gravity
-                            if off.is_down() {
-                                new_water.velocity = MAX_VELOCITY.min(new_water.velocity + GRAVITY);
+                            // Check if the movement is down and apply gravity, else spread to the side
+                            if gravity.is_down(&off) {
+                                self.velocity = gravity.fall(&self.velocity);
+                            } else {
+                                self.velocity = Velocity::from_offset(&off, side_speed);
                             }
                             break;
                         }
                         Some(other) => {
-                            if self.can_switch_with(other) {
-                                new_water.movement = off;
+                            if particle.can_switch_with(other) {
                                 // Apply some slowdown as if by friction of switching
-                                new_water.velocity = DEFAULT_VELOCITY.max(new_water.velocity - SWITCH_SLOWDOWN);
+                                self.velocity = self.velocity.switching(&off);
                                 break;
                             }
                         }
                     }
                 }
             } else {
-                new_water.movement = Offset::zero();
-                new_water.velocity = DEFAULT_VELOCITY;
+                self.velocity = gravity.rest(&self.velocity);
             }
         );
 
-        // Check number of neighbors that are IsBurning and AntiBurn
-        let mut count = 0;
-        for opt in neigborhood.iter() {
-            if let Some(cell) = opt {
-                if let Some(neigh) = cell.get_particle() {
-                    match neigh.get_burnability() {
-                        Burnability::IsBurning(_) => count += 1,
-                        Burnability::AntiBurn => count -= 1,
-                        _ => {}
-                    }
-                }
-            }
-        }
-
-        if count > 0 {
-            ParticleChange::Changed(Some(Vapor::new()))
-        } else {
-            ParticleChange::Changed(Some(Box::new(new_water)))
-        }
+        self.movement = self.velocity.movement(&mut self.carry);
     }
 }
@@ -5,16 +5,18 @@ use crate::{Color, Offset};
 
 use super::particle::MatterType;
 use super::properties::PropertyCheckResult;
-use super::{Burnability, ParticleChange};
+use super::{Burnability, Corrodibility, Fire, ParticleChange};
 
-const COLOR: u32 = 0x3D1812;
+pub(super) const COLOR: u32 = 0x3D1812;
 const DENSITY: u8 = MAX_DENSITY;
 const BURNABILITY_TIME: u8 = 150;
+const CORROSION_RESISTANCE: u8 = 8;
 
 #[derive(Clone)]
 pub struct Wood {
     color: Color,
     burnability: Burnability,
+    corrodibility: Corrodibility,
 }
 
 impl Wood {
@@ -22,6 +24,7 @@ impl Wood {
         Box::new(Wood {
             color: Color::hex(COLOR).similiar(),
             burnability: Burnability::CanBurn,
+            corrodibility: Corrodibility::Corrodible(CORROSION_RESISTANCE),
         })
     }
 }
@@ -51,6 +54,14 @@ impl Particle for Wood {
         self.burnability = new_burnability;
     }
 
+    fn get_corrodibility(&self) -> Corrodibility {
+        self.corrodibility
+    }
+
+    fn set_corrodibility(&mut self, new_corrodibility: Corrodibility) -> () {
+        self.corrodibility = new_corrodibility;
+    }
+
     fn get_movement(&self) -> Offset {
         Offset::zero()
     }
@@ -58,18 +69,32 @@ impl Particle for Wood {
     fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
         let mut new_wood = self.clone();
 
+        // Dissolve in acid
+        let corroded = Corrodibility::check(&mut new_wood, &neigborhood);
+        if let PropertyCheckResult::Destroyed = corroded {
+            return ParticleChange::Changed(None);
+        }
+
         let res = Burnability::check(&mut new_wood, &neigborhood, BURNABILITY_TIME, true);
 
         match res {
             PropertyCheckResult::Updated => {
                 if let Burnability::IsBurning(_) = new_wood.get_burnability() {
                     new_wood.color = Color::hex(FIRE_COLOR).similiar();
+
+                    // Burning particle emits flames above it
+                    if let Some(flame) = Fire::flame_above(&neigborhood) {
+                        return ParticleChange::ChangedWithEffect(Some(Box::new(new_wood)), flame);
+                    }
                 }
 
                 ParticleChange::Changed(Some(Box::new(new_wood)))
             }
             PropertyCheckResult::Destroyed => ParticleChange::Changed(None),
-            PropertyCheckResult::None => ParticleChange::None,
+            PropertyCheckResult::None => match corroded {
+                PropertyCheckResult::Updated => ParticleChange::Changed(Some(Box::new(new_wood))),
+                _ => ParticleChange::None,
+            },
         }
     }
 
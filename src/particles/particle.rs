@@ -1,31 +1,37 @@
+use std::any::{Any, TypeId};
+
 use dyn_clone::DynClone;
 
 use super::constants::MAX_DENSITY;
-use super::{constants::DEFAULT_VELOCITY, Burnability};
+use super::{constants::DEFAULT_VELOCITY, Burnability, Conductivity, Corrodibility, Freezability};
 use crate::offset::Offset;
+use crate::AreaEffect;
 use crate::Color;
 use crate::Neighborhood;
+use crate::Velocity;
 
 /// Similiar to Option.
 /// Contains information if the particle has changed or not.
 pub enum ParticleChange {
     /// Particle has changed to Some other, or to None (was destroyed)
     Changed(Option<Box<dyn Particle>>),
+    /// Particle has changed as in `Changed` and also causes an effect on the area around it
+    ChangedWithEffect(Option<Box<dyn Particle>>, AreaEffect),
     /// No change
     None,
 }
 
 impl ParticleChange {
-    /// Returns true if the variant is Changed
+    /// Returns true if the variant is Changed or ChangedWithEffect
     pub fn has_changed(&self) -> bool {
         match self {
-            ParticleChange::Changed(_) => true,
+            ParticleChange::Changed(_) | ParticleChange::ChangedWithEffect(_, _) => true,
             ParticleChange::None => false,
         }
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum MatterType {
     Solid,
     Liquid,
@@ -37,7 +43,7 @@ dyn_clone::clone_trait_object!(Particle);
 
 /// A trait that all particle types implement.
 /// To create your own particle types implement this trait.
-pub trait Particle: Send + Sync + DynClone {
+pub trait Particle: Any + Send + Sync + DynClone {
     /// Creates a new instance of this particle.
     // fn new() -> Self;
 
@@ -59,7 +65,8 @@ pub trait Particle: Send + Sync + DynClone {
     /// The returned number is an 8bit unsigned integer (0-255).
     fn get_density(&self) -> u8;
 
-    /// Movement of particle is equal to the direction it wants to travel in times its velocity.
+    /// Movement of particle is the whole number of cells it wants to travel during this update.
+    /// It is derived from the velocity, see `Velocity::movement`.
     fn get_movement(&self) -> Offset;
 
     /// Returns true if the particle is moveable (can move).
@@ -74,7 +81,7 @@ pub trait Particle: Send + Sync + DynClone {
     /// This is the default implementation, can be overriden for custom behavior.
     fn can_switch_with(&self, other: &Box<dyn Particle>) -> bool {
         self.get_density() > other.get_density()
-            || (self.get_velocity() > DEFAULT_VELOCITY
+            || (self.get_velocity().magnitude() > DEFAULT_VELOCITY
                 && self.get_density() != other.get_density()
                 && !other.is_solid())
     }
@@ -88,6 +95,65 @@ pub trait Particle: Send + Sync + DynClone {
 
     fn set_burnability(&mut self, _new_burnability: Burnability) -> () {}
 
+    /// Returns the Freezability of this particle. By default is Freezability::None.
+    fn get_freezability(&self) -> Freezability {
+        Freezability::None
+    }
+
+    fn set_freezability(&mut self, _new_freezability: Freezability) -> () {}
+
+    /// Returns the Corrodibility of this particle. By default is Corrodibility::Immune.
+    fn get_corrodibility(&self) -> Corrodibility {
+        Corrodibility::Immune
+    }
+
+    fn set_corrodibility(&mut self, _new_corrodibility: Corrodibility) -> () {}
+
+    /// Returns the Conductivity of this particle. By default is Conductivity::None.
+    fn get_conductivity(&self) -> Conductivity {
+        Conductivity::None
+    }
+
+    /// Returns true if the particle is part of a powered circuit.
+    fn is_charged(&self) -> bool {
+        false
+    }
+
+    /// Called by the simulation every update for particles that have some Conductivity.
+    /// By default does nothing.
+    fn set_charged(&mut self, _charged: bool) -> () {}
+
+    /// Returns how much lifetime does a creature (like fly) gain by eating this particle.
+    /// By default is 0, meaning the particle is not edible.
+    fn get_nutrition(&self) -> u32 {
+        0
+    }
+
+    /// Called by the simulation when the particle is hit by another particle or pushed by an explosion.
+    /// The `force` is the speed of the hit scaled by the density of the hitting particle.
+    /// By default the particle withstands any impact.
+    fn on_impact(&self, _force: f32) -> ParticleChange {
+        ParticleChange::None
+    }
+
+    /// Returns true if the particle survives explosions and shields the particles behind it.
+    fn blocks_explosions(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the particle explodes once it burns out (like TNT).
+    /// Explosives caught in the crater of another explosion are not destroyed, they detonate on their next update instead.
+    fn is_explosive(&self) -> bool {
+        false
+    }
+
+    /// Returns how far does the particle see in each direction.
+    /// The neighborhood passed to `update` will be of size (2 * radius + 1) squared.
+    /// By default the radius is 1, meaning a 3x3 neighborhood.
+    fn get_neighborhood_radius(&self) -> u32 {
+        1
+    }
+
     /// Returns a new state of the particle based on it's neighborhood.
     /// By default returns None, meaning no update of inner state
     fn update(&self, _neigborhood: Neighborhood) -> ParticleChange {
@@ -96,8 +162,20 @@ pub trait Particle: Send + Sync + DynClone {
 
     // VELOCITY
 
-    /// Returns the current velocity of this particle.
-    fn get_velocity(&self) -> f32 {
-        DEFAULT_VELOCITY
+    /// Returns the current velocity of this particle. By default the particle has no velocity.
+    fn get_velocity(&self) -> Velocity {
+        Velocity::zero()
+    }
+
+    /// Sets the velocity of this particle. Used by the simulation to transfer momentum on collisions.
+    /// By default does nothing, meaning the particle ignores any outside momentum.
+    fn set_velocity(&mut self, _new_velocity: Velocity) -> () {}
+}
+
+impl dyn Particle {
+    /// Returns true if the particle is of the type `T`, for example `particle.is::<Water>()`.
+    pub fn is<T: Particle>(&self) -> bool {
+        let any: &dyn Any = self;
+        any.type_id() == TypeId::of::<T>()
     }
 }
@@ -58,7 +58,7 @@ impl Burnability {
         // If there is more AntiBurn => particle will cease to be
         let mut burning_count = 0;
         let mut antiburn_count = 0;
-        for opt in neigborhood.iter() {
+        for opt in neigborhood.adjacent() {
             if let Some(cell) = opt {
                 if let Some(neigh) = cell.get_particle() {
                     match neigh.get_burnability() {
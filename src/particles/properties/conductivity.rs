@@ -0,0 +1,15 @@
+/// Describes how does the particle react to electric charge.
+/// The charge is spread by the simulation every update, see `Particle::set_charged`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Conductivity {
+    /// The particle is a source of charge (like battery).
+    Source,
+    /// The particle passes the charge on (like metal).
+    Conductor,
+    /// The particle passes the charge on but shorts the circuit (like water), consumers in the circuit get no charge.
+    Shorting,
+    /// The particle is powered by the charge but does not pass it on (like lamp).
+    Consumer,
+    /// The particle does not react to charge in any way.
+    None,
+}
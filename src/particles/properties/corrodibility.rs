@@ -0,0 +1,70 @@
+use crate::particles::Particle;
+use crate::Neighborhood;
+
+use super::PropertyCheckResult;
+
+/// Describes how does the particle react to acid.
+#[derive(Clone, Copy)]
+pub enum Corrodibility {
+    /// The particle corrodes other particles (like acid) and is used up in the process.
+    /// Contains a durability parameter (How long can it corrode before it is used up).
+    Corrosive(u8),
+    /// The particle gets dissolved if next to a corrosive particle.
+    /// Contains a durability parameter (How long before the particle is dissolved), it is the resistance of the particle.
+    Corrodible(u8),
+    /// The particle can not be corroded.
+    Immune,
+}
+
+impl Corrodibility {
+    pub fn decreased_by(&self, value: u8) -> Corrodibility {
+        match self {
+            Self::Corrosive(time) => Self::Corrosive(time.saturating_sub(value)),
+            Self::Corrodible(time) => Self::Corrodible(time.saturating_sub(value)),
+            Self::Immune => *self,
+        }
+    }
+
+    /// Checks the corrodibility property of the particle and returns a `PropertyCheckResult` variant.
+    ///
+    /// Possible outcomes:
+    ///     - The particle is `Corrodible` => 'Time' is decreased by the number of `Corrosive` neighbors, when it runs out the particle gets destroyed (dissolved).
+    ///     - The particle is `Corrosive` => 'Time' is decreased by 1 if there is any `Corrodible` neighbor, when it runs out the particle gets destroyed (used up).
+    pub fn check<T: Particle>(particle: &mut T, neigborhood: &Neighborhood) -> PropertyCheckResult {
+        // Check how many neighbors are Corrosive and how many are Corrodible
+        let mut corrosive_count = 0;
+        let mut corrodible_count = 0;
+        for opt in neigborhood.adjacent() {
+            if let Some(cell) = opt {
+                if let Some(neigh) = cell.get_particle() {
+                    match neigh.get_corrodibility() {
+                        Corrodibility::Corrosive(_) => corrosive_count += 1,
+                        Corrodibility::Corrodible(_) => corrodible_count += 1,
+                        Corrodibility::Immune => {}
+                    }
+                }
+            }
+        }
+
+        let decrease = match particle.get_corrodibility() {
+            Corrodibility::Corrosive(_) => corrodible_count.min(1),
+            Corrodibility::Corrodible(_) => corrosive_count,
+            Corrodibility::Immune => 0,
+        };
+
+        if decrease == 0 {
+            return PropertyCheckResult::None;
+        }
+
+        let new_corrodibility = particle.get_corrodibility().decreased_by(decrease);
+        match new_corrodibility {
+            Corrodibility::Corrosive(0) | Corrodibility::Corrodible(0) => {
+                PropertyCheckResult::Destroyed
+            }
+            _ => {
+                particle.set_corrodibility(new_corrodibility);
+                PropertyCheckResult::Updated
+            }
+        }
+    }
+}
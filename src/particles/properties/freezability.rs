@@ -0,0 +1,81 @@
+use crate::particles::Particle;
+use crate::Neighborhood;
+
+use super::{Burnability, PropertyCheckResult};
+
+/// Describes how does the particle react to cold.
+#[derive(Clone, Copy)]
+pub enum Freezability {
+    /// The particle is frozen, it freezes other particles and melts if next to something burning.
+    /// Contains a durability parameter (How long before the particle melts).
+    Frozen(u8),
+    /// The particle freezes if it stays next to frozen particles.
+    /// Contains a durability parameter (How long before the particle freezes).
+    CanFreeze(u8),
+    /// The particle does not react to cold in any way.
+    None,
+}
+
+impl Freezability {
+    pub fn decreased_by(&self, value: u8) -> Freezability {
+        match self {
+            Self::Frozen(time) => Self::Frozen(time.saturating_sub(value)),
+            Self::CanFreeze(time) => Self::CanFreeze(time.saturating_sub(value)),
+            Self::None => *self,
+        }
+    }
+
+    /// Checks the freezability property of the particle and returns a `PropertyCheckResult` variant.
+    ///
+    /// Possible outcomes:
+    ///     - The particle is `Frozen` and next to an `IsBurning` particle => 'Time' is decreased by 1, when it runs out the particle gets destroyed (melts).
+    ///     - The particle `CanFreeze`, is next to a `Frozen` particle and nothing is burning around => 'Time' is decreased by 1, when it runs out the particle gets destroyed (freezes).
+    ///     - The particle `CanFreeze` but is no longer next to a `Frozen` particle => 'Time' is reset to the `default_freeze_time`.
+    /// The particle itself decides what it turns into when destroyed.
+    pub fn check<T: Particle>(
+        particle: &mut T,
+        neigborhood: &Neighborhood,
+        default_freeze_time: u8,
+    ) -> PropertyCheckResult {
+        // Check how many neighbors are IsBurning and how many are Frozen
+        let mut burning_count = 0;
+        let mut frozen_count = 0;
+        for opt in neigborhood.adjacent() {
+            if let Some(cell) = opt {
+                if let Some(neigh) = cell.get_particle() {
+                    if let Burnability::IsBurning(_) = neigh.get_burnability() {
+                        burning_count += 1;
+                    }
+                    if let Freezability::Frozen(_) = neigh.get_freezability() {
+                        frozen_count += 1;
+                    }
+                }
+            }
+        }
+
+        match particle.get_freezability() {
+            Freezability::Frozen(time) if burning_count > 0 => {
+                if time == 0 {
+                    return PropertyCheckResult::Destroyed;
+                }
+                particle.set_freezability(Freezability::Frozen(time - 1));
+                PropertyCheckResult::Updated
+            }
+            Freezability::CanFreeze(time) => {
+                if frozen_count > 0 && burning_count == 0 {
+                    if time == 0 {
+                        return PropertyCheckResult::Destroyed;
+                    }
+                    particle.set_freezability(Freezability::CanFreeze(time - 1));
+                    PropertyCheckResult::Updated
+                } else if time != default_freeze_time {
+                    particle.set_freezability(Freezability::CanFreeze(default_freeze_time));
+                    PropertyCheckResult::Updated
+                } else {
+                    PropertyCheckResult::None
+                }
+            }
+            _ => PropertyCheckResult::None,
+        }
+    }
+}
@@ -1,5 +1,11 @@
 mod burnability;
 mod check_result;
+mod conductivity;
+mod corrodibility;
+mod freezability;
 
 pub use burnability::Burnability;
 pub use check_result::PropertyCheckResult;
+pub use conductivity::Conductivity;
+pub use corrodibility::Corrodibility;
+pub use freezability::Freezability;
@@ -0,0 +1,59 @@
+use super::*;
+
+/// Description of a particle type, so it can be looked up by its name or color.
+#[derive(Clone, Copy)]
+pub struct ParticleType {
+    /// Same as the name returned by `Particle::get_name`
+    pub name: &'static str,
+    /// Base color of the particle in RGB format, new particles have colors similiar to it
+    pub color: u32,
+    /// Function to call to create a new particle of this type
+    pub new: fn() -> Box<dyn Particle>,
+}
+
+macro_rules! particle_type {
+    ($name:expr, $module:ident, $particle:ident) => {
+        ParticleType {
+            name: $name,
+            color: $module::COLOR,
+            new: $particle::new,
+        }
+    };
+}
+
+/// All the particle types in the simulation.
+pub const PARTICLE_TYPES: [ParticleType; 28] = [
+    particle_type!("Sand", p_sand, Sand),
+    particle_type!("Water", p_water, Water),
+    particle_type!("Rock", p_rock, Rock),
+    particle_type!("Mud", p_mud, Mud),
+    particle_type!("Oil", p_oil, Oil),
+    particle_type!("Wood", p_wood, Wood),
+    particle_type!("Spark", p_spark, Spark),
+    particle_type!("Fly", p_fly, Fly),
+    particle_type!("Smoke", p_smoke, Smoke),
+    particle_type!("Vapor", p_vapor, Vapor),
+    particle_type!("Gunpowder", p_gunpowder, Gunpowder),
+    particle_type!("TNT", p_tnt, Tnt),
+    particle_type!("Lava", p_lava, Lava),
+    particle_type!("Ice", p_ice, Ice),
+    particle_type!("Snow", p_snow, Snow),
+    particle_type!("Acid", p_acid, Acid),
+    particle_type!("Seed", p_seed, Seed),
+    particle_type!("Plant", p_plant, Plant),
+    particle_type!("Fish", p_fish, Fish),
+    particle_type!("Metal", p_metal, Metal),
+    particle_type!("Battery", p_battery, Battery),
+    particle_type!("Lamp", p_lamp, Lamp),
+    particle_type!("Igniter", p_igniter, Igniter),
+    particle_type!("Salt", p_salt, Salt),
+    particle_type!("Salt Water", p_salt_water, SaltWater),
+    particle_type!("Concrete", p_concrete, Concrete),
+    particle_type!("Glass", p_glass, Glass),
+    particle_type!("Fire", p_fire, Fire),
+];
+
+/// Returns the particle type with the given name, if there is such.
+pub fn find_particle_type(name: &str) -> Option<&'static ParticleType> {
+    PARTICLE_TYPES.iter().find(|t| t.name == name)
+}
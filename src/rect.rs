@@ -0,0 +1,55 @@
+use crate::offset::Offset;
+
+/// Axis aligned rectangle of cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    /// Top left corner of the rectangle
+    pub offset: Offset,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn new(offset: Offset, width: u32, height: u32) -> Rect {
+        Rect {
+            offset,
+            width,
+            height,
+        }
+    }
+
+    /// Creates the smallest rectangle containing both of the corners.
+    pub fn from_corners(a: &Offset, b: &Offset) -> Rect {
+        let offset = Offset::new(a.x.min(b.x), a.y.min(b.y));
+        let width = (a.x - b.x).unsigned_abs() + 1;
+        let height = (a.y - b.y).unsigned_abs() + 1;
+
+        Rect::new(offset, width, height)
+    }
+
+    /// Returns true if the offset is inside the rectangle.
+    pub fn contains(&self, offset: &Offset) -> bool {
+        offset.x >= self.offset.x
+            && offset.y >= self.offset.y
+            && offset.x < self.offset.x + self.width as i32
+            && offset.y < self.offset.y + self.height as i32
+    }
+
+    /// Returns the offset in the middle of the rectangle.
+    pub fn center(&self) -> Offset {
+        self.offset + Offset::new(self.width as i32 / 2, self.height as i32 / 2)
+    }
+
+    /// Returns all offsets inside the rectangle, row by row.
+    pub fn offsets(&self) -> Vec<Offset> {
+        let mut offsets = Vec::with_capacity(self.width as usize * self.height as usize);
+
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                offsets.push(self.offset + Offset::new(x, y));
+            }
+        }
+
+        offsets
+    }
+}
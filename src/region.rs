@@ -0,0 +1,97 @@
+use crate::particles::Particle;
+
+/// Copy of a rectangular part of the simulation, with particles in their full inner state.
+/// Created by `Simulation::extract_region` and inserted back by `Simulation::paste_region`.
+#[derive(Clone)]
+pub struct Region {
+    /// Particles row by row, None for empty cells
+    particles: Vec<Option<Box<dyn Particle>>>,
+    width: u32,
+    height: u32,
+}
+
+/// Defines how a pasted region is combined with the particles already in the simulation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PasteMode {
+    /// The area becomes exactly the region, including its empty cells
+    Overwrite,
+    /// Particles of the region replace the ones in the simulation, empty cells of the region are skipped
+    Merge,
+    /// Particles of the region are pasted only into empty cells
+    FillEmpty,
+}
+
+impl Region {
+    /// Creates a region from particles row by row. Panics if their count does not match the dimensions.
+    pub fn new(particles: Vec<Option<Box<dyn Particle>>>, width: u32, height: u32) -> Region {
+        assert_eq!(particles.len(), (width * height) as usize);
+
+        Region {
+            particles,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a new region with cells copied from the positions in this region given by the function.
+    fn remapped(
+        &self,
+        width: u32,
+        height: u32,
+        position_fn: impl Fn(u32, u32) -> (u32, u32),
+    ) -> Region {
+        let mut particles = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let (from_x, from_y) = position_fn(x, y);
+                particles.push(self.get_particle(from_x, from_y).clone());
+            }
+        }
+
+        Region {
+            particles,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the particle on the position in the region. Panics if the position is out of the region.
+    pub fn get_particle(&self, x: u32, y: u32) -> &Option<Box<dyn Particle>> {
+        &self.particles[(y * self.width + x) as usize]
+    }
+
+    /// Returns the number of particles in the region.
+    pub fn particle_count(&self) -> usize {
+        self.particles.iter().filter(|p| p.is_some()).count()
+    }
+
+    /// Returns the region rotated clockwise by 90 degrees `quarter_turns` times. Negative turns rotate counterclockwise.
+    pub fn rotated(&self, quarter_turns: i32) -> Region {
+        let (w, h) = (self.width, self.height);
+
+        match quarter_turns.rem_euclid(4) {
+            1 => self.remapped(h, w, |x, y| (y, h - 1 - x)),
+            2 => self.remapped(w, h, |x, y| (w - 1 - x, h - 1 - y)),
+            3 => self.remapped(h, w, |x, y| (w - 1 - y, x)),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the region mirrored from left to right.
+    pub fn flipped_horizontally(&self) -> Region {
+        self.remapped(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    /// Returns the region mirrored from top to bottom.
+    pub fn flipped_vertically(&self) -> Region {
+        self.remapped(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+}
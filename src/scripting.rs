@@ -0,0 +1,585 @@
+//! Embedded [Rhai](https://rhai.rs) scripting of scenes and particle behaviors.
+//! Available with the `scripting` feature.
+//!
+//! Scene scripts get the simulation in the `sim` variable:
+//! ```rhai
+//! sim.fill(0, 90, 100, 10, "Rock");
+//! sim.add(50, 10, "Sand");
+//! sim.step(100);
+//! ```
+//!
+//! Particle scripts define `fn update(n)` that is called on each update with the neighborhood `n`.
+//! It can return a map with the keys `move` (offset `[x, y]` to move by), `become` (name of the particle
+//! to change into, empty to be destroyed), `spawn` and `spawn_at` (name of the particle to spawn on the offset, up by default).
+//! The names may be of built-in particles or of particles defined by the same `Scripting`, unknown names are errors.
+//! Moves are limited to the maximal velocity of particles and spawns to the neighborhood.
+//! `spawn` is a reserved word in Rhai, so the key has to be quoted: `#{ "spawn": "Vapor" }`.
+//! Inner state of the particle is kept in `this`, created by the optional `fn init()`.
+//! A particle whose script fails stays as it is, the first error is kept by its type (see `ScriptedType::error`).
+
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
+
+use rhai::{Array, CallFnOptions, Dynamic, Engine, EvalAltResult, Map, Scope, AST};
+
+use crate::particles::{
+    constants::MAX_VELOCITY, find_particle_type, Burnability, MatterType, Particle, ParticleChange,
+};
+use crate::{AreaEffect, Color, Gravity, Neighborhood, Offset, Rect, Simulation, Velocity};
+
+/// Error returned by scripts that failed to compile or run.
+pub type ScriptError = Box<EvalAltResult>;
+
+/// Most operations a single run of a script may take, so an endless loop can not hang the simulation
+const MAX_OPERATIONS: u64 = 1_000_000;
+/// Deepest nesting of function calls in a script
+const MAX_CALL_LEVELS: usize = 32;
+/// Largest width and height of a simulation created by a scene script
+const MAX_SCENE_SIZE: usize = 2048;
+
+/// Runs scene scripts and holds the particle types defined by scripts.
+pub struct Scripting {
+    engine: Arc<Engine>,
+    /// Shared with the particle types, so their scripts can create each other
+    particle_types: Arc<RwLock<Vec<Arc<ScriptedType>>>>,
+}
+
+impl Scripting {
+    pub fn new() -> Scripting {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(MAX_CALL_LEVELS);
+        register_random(&mut engine);
+        register_scene(&mut engine);
+        register_neighborhood(&mut engine);
+
+        Scripting {
+            engine: Arc::new(engine),
+            particle_types: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Defines a new particle type whose behavior is given by the script. See the module documentation for the format.
+    /// The name must be different from all the other particle types.
+    pub fn define_particle(
+        &mut self,
+        name: &str,
+        color: u32,
+        density: u8,
+        matter_type: MatterType,
+        script: &str,
+    ) -> Result<Arc<ScriptedType>, ScriptError> {
+        if self.create(name).is_some() {
+            return Err(format!("Particle \"{}\" is already defined", name).into());
+        }
+
+        let ast = self.engine.compile(script)?;
+        if !has_function(&ast, "update", 1) {
+            return Err("Particle script must define fn update(n)".into());
+        }
+
+        let particle_type = Arc::new(ScriptedType {
+            name: name.to_string(),
+            color,
+            density,
+            matter_type,
+            ast,
+            engine: self.engine.clone(),
+            error: OnceLock::new(),
+            particle_types: Arc::downgrade(&self.particle_types),
+        });
+        self.particle_types
+            .write()
+            .unwrap()
+            .push(particle_type.clone());
+
+        Ok(particle_type)
+    }
+
+    /// Creates a new particle with the name, either a built-in one or one defined by a script.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Particle>> {
+        create_particle(name, &self.particle_types.read().unwrap())
+    }
+
+    /// Runs the scene script on the simulation. Changes made before an error are kept.
+    pub fn run_scene(&self, script: &str, sim: &mut Simulation) -> Result<(), ScriptError> {
+        let handle = SceneHandle {
+            sim: Arc::new(Mutex::new(std::mem::replace(sim, Simulation::new(0, 0)))),
+            particle_types: Arc::new(self.particle_types.read().unwrap().clone()),
+        };
+        let mut scope = Scope::new();
+        scope.push("sim", handle.clone());
+
+        let result = self.engine.run_with_scope(&mut scope, script);
+
+        // Take the simulation out, the script may have replaced it with a new one
+        let handle = scope.get_value::<SceneHandle>("sim").unwrap_or(handle);
+        *sim = std::mem::replace(&mut *handle.sim.lock().unwrap(), Simulation::new(0, 0));
+
+        result.map(|_| ())
+    }
+}
+
+impl Default for Scripting {
+    fn default() -> Self {
+        Scripting::new()
+    }
+}
+
+/// Particle type with behavior defined by a script.
+pub struct ScriptedType {
+    name: String,
+    color: u32,
+    density: u8,
+    matter_type: MatterType,
+    ast: AST,
+    engine: Arc<Engine>,
+    /// First error the script failed with
+    error: OnceLock<String>,
+    /// All types defined by the same `Scripting`, weak so the types do not keep each other alive
+    particle_types: Weak<RwLock<Vec<Arc<ScriptedType>>>>,
+}
+
+impl ScriptedType {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the first error the script failed with while creating or updating particles of this type.
+    /// Errors are not returned by the updates, so this is the place to look when scripted particles do nothing.
+    pub fn error(&self) -> Option<&str> {
+        self.error.get().map(|error| error.as_str())
+    }
+
+    /// Creates a particle for the `become` and `spawn` actions, either a built-in one or one defined by the same `Scripting`.
+    fn create_other(&self, name: &str) -> Result<Box<dyn Particle>, ScriptError> {
+        let particle = match self.particle_types.upgrade() {
+            Some(particle_types) => create_particle(name, &particle_types.read().unwrap()),
+            None => create_particle(name, &[]),
+        };
+
+        particle.ok_or(format!("Unknown particle \"{}\"", name).into())
+    }
+
+    /// Keeps the error if it is the first one, the following ones are most likely the same.
+    fn report(&self, error: ScriptError) -> () {
+        let _ = self.error.set(error.to_string());
+    }
+
+    /// Creates a new particle of this type. The inner state is created by the `init` function of the script if there is one.
+    pub fn create(self: &Arc<Self>) -> Box<dyn Particle> {
+        let state = if has_function(&self.ast, "init", 0) {
+            let options = CallFnOptions::new().eval_ast(false);
+            self.engine
+                .call_fn_with_options::<Dynamic>(options, &mut Scope::new(), &self.ast, "init", ())
+                .unwrap_or_else(|error| {
+                    self.report(error);
+                    Dynamic::from_map(Map::new())
+                })
+        } else {
+            Dynamic::from_map(Map::new())
+        };
+
+        Box::new(ScriptedParticle {
+            kind: self.clone(),
+            color: Color::hex(self.color).similiar(),
+            state,
+            velocity: Velocity::zero(),
+            movement: Offset::zero(),
+        })
+    }
+}
+
+#[derive(Clone)]
+struct ScriptedParticle {
+    kind: Arc<ScriptedType>,
+    color: Color,
+    /// Inner state available to the script as `this`
+    state: Dynamic,
+    /// Velocity given by outside forces, the movement is given only by the script
+    velocity: Velocity,
+    movement: Offset,
+}
+
+impl Particle for ScriptedParticle {
+    fn get_name(&self) -> &str {
+        &self.kind.name
+    }
+
+    fn get_color(&self) -> &Color {
+        &self.color
+    }
+
+    fn get_matter_type(&self) -> &MatterType {
+        &self.kind.matter_type
+    }
+
+    fn get_density(&self) -> u8 {
+        self.kind.density
+    }
+
+    fn get_velocity(&self) -> Velocity {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, new_velocity: Velocity) -> () {
+        self.velocity = new_velocity;
+    }
+
+    fn is_moveable(&self) -> bool {
+        true
+    }
+
+    fn get_movement(&self) -> Offset {
+        self.movement
+    }
+
+    fn update(&self, neigborhood: Neighborhood) -> ParticleChange {
+        let mut new_particle = self.clone();
+        new_particle.movement = Offset::zero();
+
+        let view = ScriptNeighborhood::new(&neigborhood);
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut new_particle.state);
+        let result = self.kind.engine.call_fn_with_options::<Dynamic>(
+            options,
+            &mut Scope::new(),
+            &self.kind.ast,
+            "update",
+            (view,),
+        );
+
+        let actions = match result {
+            Ok(actions) => actions.try_cast::<Map>(),
+            Err(error) => {
+                self.kind.report(error);
+                None
+            }
+        };
+
+        // Failing script leaves the particle as it is
+        let Some(actions) = actions else {
+            return ParticleChange::Changed(Some(Box::new(new_particle)));
+        };
+
+        // Invalid offsets are reported and the action is skipped as well
+        if let Some(value) = actions.get("move") {
+            match to_offset(value) {
+                Ok(offset) => new_particle.movement = clamp_offset(offset, MAX_VELOCITY as i32),
+                Err(error) => self.kind.report(error),
+            }
+        }
+
+        // Unknown names are reported and the action is skipped
+        let particle: Option<Box<dyn Particle>> = match actions.get("become") {
+            Some(name) if name.to_string().is_empty() => None,
+            Some(name) => match self.kind.create_other(&name.to_string()) {
+                Ok(particle) => Some(particle),
+                Err(error) => {
+                    self.kind.report(error);
+                    Some(Box::new(new_particle))
+                }
+            },
+            None => Some(Box::new(new_particle)),
+        };
+
+        let spawned = match actions.get("spawn") {
+            Some(name) => match self.kind.create_other(&name.to_string()) {
+                Ok(spawned) => Some(spawned),
+                Err(error) => {
+                    self.kind.report(error);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        match spawned {
+            Some(spawned) => {
+                let offset = match actions.get("spawn_at").map(to_offset) {
+                    Some(Ok(offset)) => clamp_offset(offset, neigborhood.radius() as i32),
+                    Some(Err(error)) => {
+                        self.kind.report(error);
+                        return ParticleChange::Changed(particle);
+                    }
+                    None => neigborhood.gravity().up(),
+                };
+                ParticleChange::ChangedWithEffect(
+                    particle,
+                    AreaEffect::Spawn {
+                        offset,
+                        particle: spawned,
+                    },
+                )
+            }
+            None => ParticleChange::Changed(particle),
+        }
+    }
+}
+
+/// Creates a built-in particle or one of the scripted particle types by its name.
+fn create_particle(name: &str, particle_types: &[Arc<ScriptedType>]) -> Option<Box<dyn Particle>> {
+    if let Some(particle_type) = find_particle_type(name) {
+        return Some((particle_type.new)());
+    }
+
+    particle_types
+        .iter()
+        .find(|t| t.name == name)
+        .map(|t| t.create())
+}
+
+fn has_function(ast: &AST, name: &str, params: usize) -> bool {
+    ast.iter_functions()
+        .any(|f| f.name == name && f.params.len() == params)
+}
+
+/// Converts array `[x, y]` into an offset.
+fn to_offset(value: &Dynamic) -> Result<Offset, ScriptError> {
+    let coordinates = value
+        .read_lock::<Array>()
+        .and_then(|array| match array.as_slice() {
+            [x, y] => Some((x.as_int().ok()?, y.as_int().ok()?)),
+            _ => None,
+        });
+
+    match coordinates {
+        Some((x, y)) => offset(x, y),
+        None => Err(format!("Offset must be an array [x, y] of integers, not {}", value).into()),
+    }
+}
+
+/// Creates an offset from script integers, which may not fit into the coordinates.
+fn offset(x: i64, y: i64) -> Result<Offset, ScriptError> {
+    match (i32::try_from(x), i32::try_from(y)) {
+        (Ok(x), Ok(y)) => Ok(Offset::new(x, y)),
+        _ => Err(format!("Offset [{}, {}] is out of range", x, y).into()),
+    }
+}
+
+/// Limits both coordinates of the offset to at most the distance from zero.
+fn clamp_offset(offset: Offset, limit: i32) -> Offset {
+    Offset::new(offset.x.clamp(-limit, limit), offset.y.clamp(-limit, limit))
+}
+
+fn offset_to_array(offset: Offset) -> Array {
+    vec![
+        Dynamic::from_int(offset.x as i64),
+        Dynamic::from_int(offset.y as i64),
+    ]
+}
+
+fn register_random(engine: &mut Engine) -> () {
+    engine.register_fn("rand", fastrand::f64);
+    engine.register_fn(
+        "rand_int",
+        |from: i64, to: i64| -> Result<i64, ScriptError> {
+            if from > to {
+                return Err(format!("Empty range {}..={} in rand_int", from, to).into());
+            }
+            Ok(fastrand::i64(from..=to))
+        },
+    );
+}
+
+/// Simulation shared with the scene script.
+#[derive(Clone)]
+struct SceneHandle {
+    sim: Arc<Mutex<Simulation>>,
+    particle_types: Arc<Vec<Arc<ScriptedType>>>,
+}
+
+impl SceneHandle {
+    fn create(&self, name: &str) -> Result<Box<dyn Particle>, ScriptError> {
+        create_particle(name, &self.particle_types)
+            .ok_or(format!("Unknown particle \"{}\"", name).into())
+    }
+}
+
+fn register_scene(engine: &mut Engine) -> () {
+    engine
+        .register_type_with_name::<SceneHandle>("Simulation")
+        .register_get("width", |s: &mut SceneHandle| {
+            s.sim.lock().unwrap().width() as i64
+        })
+        .register_get("height", |s: &mut SceneHandle| {
+            s.sim.lock().unwrap().height() as i64
+        })
+        .register_fn(
+            "reset",
+            |s: &mut SceneHandle, width: i64, height: i64| -> Result<(), ScriptError> {
+                let size = 0..=MAX_SCENE_SIZE as i64;
+                if !size.contains(&width) || !size.contains(&height) {
+                    return Err(format!(
+                        "Simulation {}x{} is not in the sizes up to {}x{}",
+                        width, height, MAX_SCENE_SIZE, MAX_SCENE_SIZE
+                    )
+                    .into());
+                }
+                *s.sim.lock().unwrap() = Simulation::new(width as usize, height as usize);
+                Ok(())
+            },
+        )
+        .register_fn(
+            "add",
+            |s: &mut SceneHandle, x: i64, y: i64, name: &str| -> Result<bool, ScriptError> {
+                let offset = offset(x, y)?;
+                let particle = s.create(name)?;
+                Ok(s.sim.lock().unwrap().add_particle(&offset, particle))
+            },
+        )
+        .register_fn(
+            "fill",
+            |s: &mut SceneHandle,
+             x: i64,
+             y: i64,
+             w: i64,
+             h: i64,
+             name: &str|
+             -> Result<(), ScriptError> {
+                let mut sim = s.sim.lock().unwrap();
+                // Only the part inside of the simulation is filled
+                let (width, height) = (sim.width() as i64, sim.height() as i64);
+                for off_y in y.max(0)..y.saturating_add(h).min(height) {
+                    for off_x in x.max(0)..x.saturating_add(w).min(width) {
+                        let particle = s.create(name)?;
+                        sim.add_particle(&Offset::new(off_x as i32, off_y as i32), particle);
+                    }
+                }
+                Ok(())
+            },
+        )
+        .register_fn(
+            "remove",
+            |s: &mut SceneHandle, x: i64, y: i64| -> Result<bool, ScriptError> {
+                let offset = offset(x, y)?;
+                Ok(s.sim.lock().unwrap().remove_particle(&offset))
+            },
+        )
+        .register_fn(
+            "name_at",
+            |s: &mut SceneHandle, x: i64, y: i64| -> Result<String, ScriptError> {
+                let offset = offset(x, y)?;
+                Ok(s.sim
+                    .lock()
+                    .unwrap()
+                    .get_particle(&offset)
+                    .as_ref()
+                    .map_or(String::new(), |p| p.get_name().to_string()))
+            },
+        )
+        .register_fn("count", |s: &mut SceneHandle, name: &str| {
+            let sim = s.sim.lock().unwrap();
+            let whole = Rect::new(Offset::zero(), sim.width() as u32, sim.height() as u32);
+            sim.count_by_name(&whole).get(name).copied().unwrap_or(0) as i64
+        })
+        .register_fn(
+            "set_gravity",
+            |s: &mut SceneHandle, angle: f64, strength: f64| {
+                s.sim
+                    .lock()
+                    .unwrap()
+                    .set_gravity(Gravity::from_angle(angle as f32, strength as f32));
+            },
+        )
+        .register_fn("step", |s: &mut SceneHandle| {
+            s.sim.lock().unwrap().simulate_step();
+        })
+        .register_fn("step", |s: &mut SceneHandle, steps: i64| {
+            let mut sim = s.sim.lock().unwrap();
+            for _ in 0..steps {
+                sim.simulate_step();
+            }
+        });
+}
+
+/// Information about a particle in the neighborhood
+#[derive(Clone)]
+struct ParticleInfo {
+    name: String,
+    density: u8,
+    is_burning: bool,
+}
+
+/// Copy of the neighborhood given to the particle scripts, so it does not borrow the simulation.
+#[derive(Clone)]
+struct ScriptNeighborhood {
+    radius: i32,
+    /// Cells row by row, None if outside of the simulation
+    cells: Vec<Option<Option<ParticleInfo>>>,
+    gravity: Gravity,
+}
+
+impl ScriptNeighborhood {
+    fn new(neigborhood: &Neighborhood) -> ScriptNeighborhood {
+        let radius = neigborhood.radius() as i32;
+        let mut cells = Vec::new();
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                cells.push(neigborhood.on_relative(&Offset::new(x, y)).map(|cell| {
+                    cell.get_particle().as_ref().map(|p| ParticleInfo {
+                        name: p.get_name().to_string(),
+                        density: p.get_density(),
+                        is_burning: matches!(p.get_burnability(), Burnability::IsBurning(_)),
+                    })
+                }));
+            }
+        }
+
+        ScriptNeighborhood {
+            radius,
+            cells,
+            gravity: *neigborhood.gravity(),
+        }
+    }
+
+    /// Returns the cell on the relative position, None if it is outside of the neighborhood or the simulation
+    fn get(&self, x: i64, y: i64) -> Option<&Option<ParticleInfo>> {
+        let r = self.radius as i64;
+        if x.abs() > r || y.abs() > r {
+            return None;
+        }
+
+        self.cells[((y + r) * (2 * r + 1) + x + r) as usize].as_ref()
+    }
+
+    fn particle(&self, x: i64, y: i64) -> Option<&ParticleInfo> {
+        self.get(x, y)?.as_ref()
+    }
+}
+
+fn register_neighborhood(engine: &mut Engine) -> () {
+    engine
+        .register_type_with_name::<ScriptNeighborhood>("Neighborhood")
+        .register_get("radius", |n: &mut ScriptNeighborhood| n.radius as i64)
+        .register_fn("is_within", |n: &mut ScriptNeighborhood, x: i64, y: i64| {
+            n.get(x, y).is_some()
+        })
+        .register_fn("is_empty", |n: &mut ScriptNeighborhood, x: i64, y: i64| {
+            matches!(n.get(x, y), Some(None))
+        })
+        .register_fn("name", |n: &mut ScriptNeighborhood, x: i64, y: i64| {
+            n.particle(x, y).map_or(String::new(), |p| p.name.clone())
+        })
+        .register_fn("density", |n: &mut ScriptNeighborhood, x: i64, y: i64| {
+            n.particle(x, y).map_or(0, |p| p.density as i64)
+        })
+        .register_fn(
+            "is_burning",
+            |n: &mut ScriptNeighborhood, x: i64, y: i64| {
+                n.particle(x, y).is_some_and(|p| p.is_burning)
+            },
+        )
+        .register_fn("up", |n: &mut ScriptNeighborhood| {
+            offset_to_array(n.gravity.up())
+        })
+        .register_fn("down", |n: &mut ScriptNeighborhood| {
+            offset_to_array(n.gravity.down())
+        })
+        .register_fn("side", |n: &mut ScriptNeighborhood, dir: i64| {
+            offset_to_array(n.gravity.side(dir.signum() as i32))
+        })
+        .register_fn("down_side", |n: &mut ScriptNeighborhood, dir: i64| {
+            offset_to_array(n.gravity.down_side(dir.signum() as i32))
+        });
+}
@@ -1,15 +1,23 @@
 use dyn_clone::clone_box;
 use fastrand;
-use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use image::ImageError;
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{collections::LinkedList, iter::zip};
 
 use crate::{
     area::Area,
+    area_effect::AreaEffect,
+    blob::Blob,
+    force_field::{density_factor, ForceField},
     offset::Offset,
-    particles::{constants::*, MatterType, Particle, ParticleChange},
+    particles::{
+        constants::*, Burnability, Conductivity, MatterType, Particle, ParticleChange, Smoke, Spark,
+    },
     sprite::Sprite,
-    Cell, Neighborhood,
+    Cell, Gravity, Neighborhood, Palette, PasteMode, Rect, Region, Velocity,
 };
 
 pub struct SimInfo {
@@ -32,12 +40,38 @@ enum SimMove {
     Switch(usize), // FROM
 }
 
+/// A particle that was stopped by an obstacle before reaching its desired offset.
+#[derive(Clone, Copy)]
+struct Collision {
+    /// Index of the particle that was stopped
+    from: usize,
+    /// Index of the particle it collided with. None if it was the edge of the simulation.
+    with: Option<usize>,
+    /// Direction in which the particle was stopped
+    direction: Offset,
+}
+
+/// Moves and collisions found in a part of the simulation
+type PartialMoves = (LinkedList<(usize, SimMove)>, LinkedList<Collision>);
+
+/// New state of a particle on an index and the effect it causes
+type ParticleUpdate = (usize, Option<Box<dyn Particle>>, Option<AreaEffect>);
+
 pub struct Simulation {
     width: usize,
     height: usize,
     cells: Vec<Cell>,
     moves: FxHashMap<usize, Vec<SimMove>>, // Destination index, Moves to be done ending at that index
+    collisions: Vec<Collision>,
+    /// Cells changed by the area effects applied so far, so later effects do not take the changes back
+    changed_by_effects: FxHashSet<usize>,
+    gravity: Gravity,
+    force_fields: Vec<ForceField>,
     sim_info: SimInfo,
+    /// Seed of the random numbers, None if the simulation is not deterministic
+    seed: Option<u64>,
+    /// Number of steps simulated so far
+    step_count: u64,
 }
 
 impl Simulation {
@@ -47,7 +81,13 @@ impl Simulation {
             height,
             cells: vec![Cell::empty(); width * height],
             moves: FxHashMap::default(),
+            collisions: Vec::new(),
+            changed_by_effects: FxHashSet::default(),
+            gravity: Gravity::default(),
+            force_fields: Vec::new(),
             sim_info: SimInfo::new(),
+            seed: None,
+            step_count: 0,
         }
     }
 
@@ -87,6 +127,9 @@ impl Simulation {
 
         cell.remove_particle();
 
+        // Update Sim Info
+        self.sim_info.particle_count -= 1;
+
         true
     }
 
@@ -98,51 +141,283 @@ impl Simulation {
         self.cells[self.offset_to_index(offset)].get_particle()
     }
 
+    /// Returns all particles inside the rectangle with their offsets, row by row.
+    pub fn particles_in_rect<'a>(
+        &'a self,
+        rect: &Rect,
+    ) -> impl Iterator<Item = (Offset, &'a Box<dyn Particle>)> + 'a {
+        let (x, y) = (rect.offset.x as i64, rect.offset.y as i64);
+        let inside = self.clip(x, y, x + rect.width as i64, y + rect.height as i64);
+
+        inside
+            .offsets()
+            .into_iter()
+            .filter_map(|off| self.get_particle(&off).as_ref().map(|p| (off, p)))
+    }
+
+    /// Returns the part of the area from the top left corner to the bottom right one (exclusive) that is inside the simulation.
+    /// Wide integers so large areas and far away corners can not overflow.
+    fn clip(&self, left: i64, top: i64, right: i64, bottom: i64) -> Rect {
+        let left = left.clamp(0, self.width as i64);
+        let top = top.clamp(0, self.height as i64);
+        let right = right.clamp(left, self.width as i64);
+        let bottom = bottom.clamp(top, self.height as i64);
+
+        Rect::new(
+            Offset::new(left as i32, top as i32),
+            (right - left) as u32,
+            (bottom - top) as u32,
+        )
+    }
+
+    /// Returns all particles with distance from the center at most the radius, with their offsets.
+    pub fn particles_in_circle<'a>(
+        &'a self,
+        center: &Offset,
+        radius: u32,
+    ) -> impl Iterator<Item = (Offset, &'a Box<dyn Particle>)> + 'a {
+        // Wide integers so large radii and far away centers can not overflow
+        let (cx, cy, r) = (center.x as i64, center.y as i64, radius as i64);
+        // Square around the circle cut to the simulation, so only the cells inside are visited
+        let square = self.clip(cx - r, cy - r, cx + r + 1, cy + r + 1);
+
+        self.particles_in_rect(&square).filter(move |(off, _)| {
+            let (dx, dy) = ((off.x as i64 - cx) as i128, (off.y as i64 - cy) as i128);
+            dx * dx + dy * dy <= r as i128 * r as i128
+        })
+    }
+
+    /// Returns number of particles of each type inside the rectangle, by their names.
+    pub fn count_by_name(&self, rect: &Rect) -> FxHashMap<String, usize> {
+        let mut counts = FxHashMap::default();
+        for (_, p) in self.particles_in_rect(rect) {
+            *counts.entry(p.get_name().to_string()).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Returns number of particles of the matter type inside the rectangle.
+    pub fn count_by_matter_type(&self, rect: &Rect, matter_type: MatterType) -> usize {
+        self.particles_in_rect(rect)
+            .filter(|(_, p)| *p.get_matter_type() == matter_type)
+            .count()
+    }
+
+    /// Finds the offset of the particle with the name that is nearest to the offset. None if there is no such particle.
+    pub fn find_nearest(&self, offset: &Offset, name: &str) -> Option<Offset> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| {
+                cell.get_particle()
+                    .as_ref()
+                    .is_some_and(|p| p.get_name() == name)
+            })
+            .map(|(index, _)| self.index_to_offset(index))
+            .min_by_key(|off| {
+                // Wide integers so far away offsets can not overflow
+                let dx = (off.x as i64 - offset.x as i64) as i128;
+                let dy = (off.y as i64 - offset.y as i64) as i128;
+                dx * dx + dy * dy
+            })
+    }
+
+    /// Finds all particles of the same type as the one on the offset that are connected to it by their sides.
+    /// None if there is no particle on the offset.
+    pub fn find_blob(&self, offset: &Offset) -> Option<Blob> {
+        let name = self.get_particle(offset).as_ref()?.get_name();
+        let is_same = |off: &Offset| {
+            self.get_particle(off)
+                .as_ref()
+                .is_some_and(|p| p.get_name() == name)
+        };
+
+        let mut offsets = Vec::new();
+        let mut visited: FxHashSet<Offset> = FxHashSet::default();
+        // BFS
+        let mut queue: LinkedList<Offset> = LinkedList::new();
+        queue.push_back(*offset);
+        visited.insert(*offset);
+        while let Some(cur) = queue.pop_front() {
+            offsets.push(cur);
+            for off in [UP, DOWN, LEFT, RIGHT] {
+                let next = cur + off;
+                if is_same(&next) && visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Some(Blob { offsets })
+    }
+
     pub fn change_particle(&mut self, offset: &Offset, new_particle: Box<dyn Particle>) -> () {
         if !self.is_within(&offset) {
             return;
         }
 
         let index = self.offset_to_index(offset);
+        // Update Sim Info
+        if self.cells[index].is_empty() {
+            self.sim_info.particle_count += 1;
+        }
+
         self.cells[index].set_particle(new_particle);
     }
 
     pub fn simulate_step(&mut self) -> () {
+        self.step_count += 1;
+        // Random numbers used in this step on this thread are given by the seed and the step
+        self.reseed(usize::MAX);
+
         // Reset moves in sim info
         self.sim_info.moves_made_last_frame = 0;
 
         self.find_moves_multithreaded();
+        let impacts = self.apply_collisions();
         self.apply_moves();
+        // Particles react to impacts only once all of them are on their new positions
+        self.apply_impacts(impacts);
 
         self.clear_moves();
 
         self.calculate_pressure();
 
+        // Push particles by the force fields, so their new velocity is used in the update
+        self.apply_force_fields();
+
+        // Spread the charge through circuits, so the consumers react to it in the update
+        self.propagate_charge();
+
         // Update inner state of particles
         self.update_inner_states();
     }
 
     /// Inserts a sprite object into the simulation.
     /// Start offset represents where the top left corner of the sprite will be.
-    /// Translate function defines how each color translates to a particle.
+    /// Translate function defines how each color translates to a particle, returning None leaves the cell as is.
+    /// Fully transparent pixels are skipped.
     pub fn insert_sprite(
         &mut self,
         sprite: Sprite,
         start_offset: &Offset,
-        translate_fn: fn(u32) -> Box<dyn Particle>,
+        translate_fn: impl FnMut(u32) -> Option<Box<dyn Particle>>,
     ) -> () {
-        for i in 0..sprite.pixels.len() {
-            // Get color
-            let color = sprite.pixels[i];
-            // Calculate offset of the particle in the simulation
-            let y = (i as u32) / sprite.width;
-            let x = (i as u32) - (y * sprite.width);
-            let p_offset = *start_offset + Offset::new(x as i32, y as i32);
+        let area = Rect::new(*start_offset, sprite.width, sprite.height);
+        self.insert_sprite_tiled(&sprite, &area, translate_fn);
+    }
+
+    /// Fills the area by repeating the sprite, starting with its top left corner in the top left corner of the area.
+    /// Colors are translated the same way as in `Simulation::insert_sprite`.
+    pub fn insert_sprite_tiled(
+        &mut self,
+        sprite: &Sprite,
+        area: &Rect,
+        mut translate_fn: impl FnMut(u32) -> Option<Box<dyn Particle>>,
+    ) -> () {
+        if sprite.width == 0 || sprite.height == 0 {
+            return;
+        }
+
+        for p_offset in area.offsets() {
+            // Get color of the pixel in the repeated sprite
+            let in_area = p_offset - area.offset;
+            let color = sprite.get_pixel(
+                in_area.x as u32 % sprite.width,
+                in_area.y as u32 % sprite.height,
+            );
+            if Sprite::is_transparent(color) {
+                continue;
+            }
+
             // Add particle into simulation
-            self.add_particle(&p_offset, translate_fn(color));
+            if let Some(particle) = translate_fn(color) {
+                self.add_particle(&p_offset, particle);
+            }
+        }
+    }
+
+    /// Copies the particles inside the rectangle. Cells outside of the simulation are copied as empty.
+    pub fn extract_region(&self, rect: &Rect) -> Region {
+        let particles = rect
+            .offsets()
+            .iter()
+            .map(|off| self.get_particle(off).clone())
+            .collect();
+
+        Region::new(particles, rect.width, rect.height)
+    }
+
+    /// Pastes the region with its top left corner at the offset. Parts of the region outside of the simulation are cut off.
+    pub fn paste_region(&mut self, region: &Region, offset: &Offset, mode: PasteMode) -> () {
+        for y in 0..region.height() {
+            for x in 0..region.width() {
+                let p_offset = *offset + Offset::new(x as i32, y as i32);
+
+                match (region.get_particle(x, y), mode) {
+                    (None, PasteMode::Overwrite) => {
+                        self.remove_particle(&p_offset);
+                    }
+                    (None, _) => {}
+                    (Some(particle), PasteMode::FillEmpty) => {
+                        self.add_particle(&p_offset, particle.clone());
+                    }
+                    (Some(particle), _) => {
+                        if !self.add_particle(&p_offset, particle.clone()) {
+                            self.change_particle(&p_offset, particle.clone());
+                        }
+                    }
+                }
+            }
         }
     }
 
+    /// Creates a sprite of the simulation where each particle is drawn in its color from the palette.
+    /// Empty cells and particles missing in the palette are fully transparent.
+    pub fn to_sprite(&self, palette: &Palette) -> Sprite {
+        let pixels = self
+            .cells
+            .iter()
+            .map(|cell| {
+                cell.get_particle()
+                    .as_ref()
+                    .and_then(|p| palette.color_of(p.get_name()))
+                    // Set max opacity
+                    .map_or(0, |color| color | 0xFF << 24)
+            })
+            .collect();
+
+        Sprite {
+            pixels,
+            width: self.width as u32,
+            height: self.height as u32,
+        }
+    }
+
+    /// Saves the simulation as a level image, that can be edited and loaded back with `Simulation::import_level`.
+    /// The palette should map each particle to an unique color, otherwise the particles may change on import.
+    pub fn export_level(&self, img_path: &str, palette: &Palette) -> Result<(), ImageError> {
+        self.to_sprite(palette).save(img_path)
+    }
+
+    /// Creates a new simulation from a level image. The simulation has the same size as the image
+    /// and each pixel is translated by the palette.
+    pub fn import_level(img_path: &str, palette: &Palette) -> Result<Simulation, ImageError> {
+        let sprite = Sprite::load(img_path)?;
+        let mut sim = Simulation::new(sprite.width as usize, sprite.height as usize);
+        sim.insert_sprite(sprite, &Offset::zero(), |color| palette.translate(color));
+
+        Ok(sim)
+    }
+
+    /// Makes the simulation deterministic, so the same simulation with the same seed always evolves the same way.
+    /// Also seeds random numbers of the current thread, so particles created after this call are the same as well.
+    pub fn set_seed(&mut self, seed: u64) -> () {
+        self.seed = Some(seed);
+        fastrand::seed(seed);
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -154,6 +429,39 @@ impl Simulation {
     pub fn info(&self) -> &SimInfo {
         &self.sim_info
     }
+
+    pub fn gravity(&self) -> &Gravity {
+        &self.gravity
+    }
+
+    /// Sets the gravity that all particles will respect from the next step on.
+    pub fn set_gravity(&mut self, gravity: Gravity) -> () {
+        self.gravity = gravity;
+    }
+
+    /// Registers a force field that will act on particles each step. Returns its index.
+    pub fn add_force_field(&mut self, field: ForceField) -> usize {
+        self.force_fields.push(field);
+
+        self.force_fields.len() - 1
+    }
+
+    /// Removes the force field on the `index`. Indexes of the fields after it are shifted down by one.
+    pub fn remove_force_field(&mut self, index: usize) -> Option<ForceField> {
+        if index < self.force_fields.len() {
+            Some(self.force_fields.remove(index))
+        } else {
+            None
+        }
+    }
+
+    pub fn force_fields(&self) -> &[ForceField] {
+        &self.force_fields
+    }
+
+    pub fn clear_force_fields(&mut self) -> () {
+        self.force_fields.clear();
+    }
 }
 
 impl Simulation {
@@ -186,24 +494,26 @@ impl Simulation {
         }
 
         // Using rayon find moves for each start end tuple
-        let vec_of_partial_moves: Vec<LinkedList<(usize, SimMove)>> = start_end_tuples
+        let vec_of_partial_moves: Vec<PartialMoves> = start_end_tuples
             .par_iter()
             .map(|(start, end)| self.find_moves_in_range(*start, *end))
             .collect();
 
-        // Join the moves into a map
-        for part in vec_of_partial_moves {
-            for (to, sim_move) in part.iter() {
+        // Join the moves into a map and collisions into a vector
+        for (moves_part, collisions_part) in vec_of_partial_moves {
+            for (to, sim_move) in moves_part.iter() {
                 self.add_move(*to, *sim_move);
             }
+            self.collisions.extend(collisions_part);
         }
     }
 
-    /// Finds desired moves for each particle
-    fn find_moves_in_range(&self, start: usize, end: usize) -> LinkedList<(usize, SimMove)> {
+    /// Finds desired moves for each particle and collisions of particles that could not move as far as they wanted
+    fn find_moves_in_range(&self, start: usize, end: usize) -> PartialMoves {
         // Use list for more efficiency. These moves still has to be copied over to the total moves.
         // Contains tuples (from, to)
         let mut moves_list: LinkedList<(usize, SimMove)> = LinkedList::new();
+        let mut collisions_list: LinkedList<Collision> = LinkedList::new();
 
         // Look at the given range
         for i in start..end {
@@ -226,7 +536,19 @@ impl Simulation {
                 }
                 // Find the maximum offset to which the particle CAN move
                 // All necceseary check are done here
-                let new_offset = self.find_max_offset(p_offset, max_offset, p);
+                let (new_offset, obstacle) = self.find_max_offset(p_offset, max_offset, p);
+                if let Some(obstacle) = obstacle {
+                    let with = if self.is_within(&obstacle) {
+                        Some(self.offset_to_index(&obstacle))
+                    } else {
+                        None
+                    };
+                    collisions_list.push_back(Collision {
+                        from: i,
+                        with,
+                        direction: obstacle - new_offset,
+                    });
+                }
                 // Convert to index
                 let new_index = self.offset_to_index(&new_offset);
                 if self.cells[new_index].is_empty() {
@@ -237,7 +559,7 @@ impl Simulation {
             }
         }
 
-        moves_list
+        (moves_list, collisions_list)
     }
 
     /// Adds a move to the moves map
@@ -249,6 +571,104 @@ impl Simulation {
         }
     }
 
+    /// Transfers momentum between the collided particles.
+    /// Moveable obstacles share the momentum with the particle as in a perfectly inelastic collision.
+    /// Otherwise the particle loses its velocity in the direction of the obstacle.
+    /// Returns the impacts on the obstacles, see `Simulation::apply_impacts`.
+    fn apply_collisions(&mut self) -> Vec<(usize, f32)> {
+        let mut impacts: Vec<(usize, f32)> = Vec::new();
+        for collision in &self.collisions {
+            let (velocity, density) = match self.cells[collision.from].get_particle() {
+                Some(p) => (p.get_velocity(), p.get_density() as f32 + 1.0),
+                None => continue,
+            };
+
+            if let Some(with) = collision.with {
+                impacts.push((
+                    with,
+                    velocity.magnitude() * density / (MAX_DENSITY as f32 + 1.0),
+                ));
+            }
+
+            // Obstacle that can be pushed
+            let other = collision
+                .with
+                .and_then(|with| match self.cells[with].get_particle() {
+                    Some(other) if other.is_moveable() => {
+                        Some((with, other.get_velocity(), other.get_density() as f32 + 1.0))
+                    }
+                    _ => None,
+                });
+
+            match other {
+                Some((with, other_velocity, other_density)) => {
+                    let shared = (velocity * density + other_velocity * other_density)
+                        * (1.0 / (density + other_density));
+
+                    if let Some(p) = self.cells[collision.from].get_particle_mut() {
+                        p.set_velocity(shared);
+                    }
+                    if let Some(other) = self.cells[with].get_particle_mut() {
+                        other.set_velocity(shared);
+                    }
+                }
+                None => {
+                    let mut stopped = velocity;
+                    if collision.direction.x != 0 {
+                        stopped.x = 0.0;
+                    }
+                    if collision.direction.y != 0 {
+                        stopped.y = 0.0;
+                    }
+
+                    if let Some(p) = self.cells[collision.from].get_particle_mut() {
+                        p.set_velocity(stopped);
+                    }
+                }
+            }
+        }
+
+        impacts
+    }
+
+    /// Lets the particles hit by a fast enough particle react to it, see `Particle::on_impact`.
+    /// Must be called after the moves are applied and before they are cleared.
+    /// Particles that took part in a move are no longer where they were hit, so they are skipped.
+    fn apply_impacts(&mut self, impacts: Vec<(usize, f32)>) -> () {
+        let mut moved: FxHashSet<usize> = FxHashSet::default();
+        for (to, move_vec) in self.moves.iter() {
+            moved.insert(*to);
+            moved.extend(move_vec.iter().map(|sim_move| match sim_move {
+                SimMove::Move(from) | SimMove::Switch(from) => *from,
+            }));
+        }
+
+        self.changed_by_effects.clear();
+        for (index, force) in impacts {
+            if !moved.contains(&index) {
+                self.apply_impact(index, force);
+            }
+        }
+    }
+
+    /// Lets the particle on the `index` react to an impact of the `force`.
+    fn apply_impact(&mut self, index: usize, force: f32) -> () {
+        let change = match self.cells[index].get_particle() {
+            Some(p) => p.on_impact(force),
+            None => return,
+        };
+
+        match change {
+            ParticleChange::Changed(opt) => self.set_cell_particle(index, opt),
+            ParticleChange::ChangedWithEffect(opt, effect) => {
+                self.set_cell_particle(index, opt);
+                self.apply_area_effect(&self.index_to_offset(index), effect);
+            }
+            ParticleChange::None => return,
+        }
+        self.changed_by_effects.insert(index);
+    }
+
     /// Apply the moves in moves map
     fn apply_moves(&mut self) -> () {
         for (to, move_vec) in self.moves.iter() {
@@ -286,22 +706,57 @@ impl Simulation {
         }
     }
 
-    /// Clears the moves map
+    /// Clears the moves map and collisions
     fn clear_moves(&mut self) -> () {
         self.moves.clear();
+        self.collisions.clear();
+    }
+
+    /// Adds the forces of all force fields to the velocity of each moveable particle
+    fn apply_force_fields(&mut self) -> () {
+        if self.force_fields.is_empty() {
+            return;
+        }
+
+        let width = self.width;
+        let fields = &self.force_fields;
+        self.cells
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, cell)| {
+                if let Some(p) = cell.get_particle_mut() {
+                    if !p.is_moveable() {
+                        return;
+                    }
+
+                    let offset = index_to_offset(width, index);
+                    let force = fields
+                        .iter()
+                        .fold(Velocity::zero(), |sum, field| sum + field.force_at(&offset));
+                    if force.is_zero() {
+                        return;
+                    }
+
+                    let force = force * density_factor(p.get_density());
+                    p.set_velocity((p.get_velocity() + force).clamped(MAX_VELOCITY));
+                }
+            });
     }
 
     /// Updates the inner state of each particle
     fn update_inner_states(&mut self) -> () {
-        // Get new particles, meaning new states
-        let new_particles: LinkedList<(usize, Option<Box<dyn Particle>>)> = self
+        // Get new particles, meaning new states, and effects they cause
+        let new_particles: LinkedList<ParticleUpdate> = self
             .cells
             .par_iter()
             .enumerate()
             .map(|(index, cell)| {
                 if let Some(p) = cell.get_particle() {
+                    // Updates run on any thread, so each particle gets its own random numbers
+                    self.reseed(index);
                     let offset = self.index_to_offset(index);
-                    let neigborhood: Neighborhood = self.get_neighborhood(offset);
+                    let neigborhood: Neighborhood =
+                        self.get_neighborhood(offset, p.get_neighborhood_radius());
                     let p_change = p.update(neigborhood);
 
                     (index, p_change)
@@ -309,30 +764,184 @@ impl Simulation {
                     (index, ParticleChange::None)
                 }
             })
-            .filter_map(|(index, p_change)| {
-                if let ParticleChange::Changed(opt) = p_change {
-                    Some((index, opt))
-                } else {
-                    None
-                }
+            .filter_map(|(index, p_change)| match p_change {
+                ParticleChange::Changed(opt) => Some((index, opt, None)),
+                ParticleChange::ChangedWithEffect(opt, effect) => Some((index, opt, Some(effect))),
+                ParticleChange::None => None,
             })
             .collect();
 
-        for (index, opt) in new_particles {
-            match opt {
-                Some(p) => self.cells[index].set_particle(p),
-                None => self.cells[index].remove_particle(),
+        let mut effects: LinkedList<(usize, AreaEffect)> = LinkedList::new();
+        for (index, opt, effect) in new_particles {
+            self.set_cell_particle(index, opt);
+
+            if let Some(effect) = effect {
+                effects.push_back((index, effect));
+            }
+        }
+
+        // Effects are applied after all particles are updated, so they see the new states
+        self.changed_by_effects.clear();
+        for (index, effect) in effects {
+            let offset = self.index_to_offset(index);
+            self.apply_area_effect(&offset, effect);
+        }
+    }
+
+    /// Applies the effect of a particle on the `center` to the area around it
+    fn apply_area_effect(&mut self, center: &Offset, effect: AreaEffect) -> () {
+        match effect {
+            AreaEffect::Explosion { radius, force } => self.explode(center, radius, force),
+            AreaEffect::Replace { offset, particle } => {
+                let target = *center + offset;
+                if !self.is_within(&target) {
+                    return;
+                }
+
+                let index = self.offset_to_index(&target);
+                if !self.cells[index].is_empty() {
+                    self.set_cell_particle(index, particle);
+                    self.changed_by_effects.insert(index);
+                }
+            }
+            AreaEffect::ReplaceIf {
+                offset,
+                condition,
+                particle,
+                fallback,
+            } => {
+                let target = *center + offset;
+                let holds = self.is_within(&target)
+                    && match self.get_particle(&target) {
+                        Some(p) => condition(p),
+                        None => false,
+                    };
+
+                if holds {
+                    let index = self.offset_to_index(&target);
+                    self.set_cell_particle(index, particle);
+                    self.changed_by_effects.insert(index);
+                } else {
+                    // The particle causing the effect may have been destroyed or replaced by an earlier effect
+                    let index = self.offset_to_index(center);
+                    if !self.changed_by_effects.contains(&index) {
+                        self.set_cell_particle(index, fallback);
+                    }
+                }
+            }
+            AreaEffect::Spawn { offset, particle } => {
+                let target = *center + offset;
+                if !self.is_within(&target) {
+                    return;
+                }
+
+                let index = self.offset_to_index(&target);
+                if self.cells[index].is_empty() {
+                    self.set_cell_particle(index, Some(particle));
+                    self.changed_by_effects.insert(index);
+                }
+            }
+        }
+    }
+
+    /// Sets the particle of the cell on the `index`, None empties the cell. Keeps the particle count up to date.
+    fn set_cell_particle(&mut self, index: usize, particle: Option<Box<dyn Particle>>) -> () {
+        match (self.cells[index].is_empty(), particle.is_none()) {
+            (true, false) => self.sim_info.particle_count += 1,
+            (false, true) => self.sim_info.particle_count -= 1,
+            _ => {}
+        }
+
+        self.cells[index].set_particle_option(particle);
+    }
+
+    /// Destroys particles in the `radius`, spawning sparks and smoke in their place,
+    /// and pushes moveable particles up to twice the `radius` away.
+    fn explode(&mut self, center: &Offset, radius: u32, force: f32) -> () {
+        let radius = radius as i32;
+        let reach = radius * 2;
+        let mut impacts: Vec<(usize, f32)> = Vec::new();
+
+        for y in -reach..=reach {
+            for x in -reach..=reach {
+                let offset = *center + Offset::new(x, y);
+                let distance = ((x * x + y * y) as f32).sqrt();
+                if distance > reach as f32 || !self.is_within(&offset) {
+                    continue;
+                }
+
+                // Particles blocking explosions shield everything behind them
+                if self.is_shielded(center, &offset) {
+                    continue;
+                }
+
+                let index = self.offset_to_index(&offset);
+                let is_explosive = self.cells[index]
+                    .get_particle()
+                    .as_ref()
+                    .is_some_and(|p| p.is_explosive());
+                if distance <= radius as f32 {
+                    self.changed_by_effects.insert(index);
+                }
+                if distance <= radius as f32 && is_explosive {
+                    // Explosives in the crater burn out right away, so they explode on their next update
+                    if let Some(p) = self.cells[index].get_particle_mut() {
+                        p.set_burnability(Burnability::IsBurning(0));
+                    }
+                } else if distance <= radius as f32 {
+                    // Inside the crater
+                    let debris: Option<Box<dyn Particle>> = match fastrand::u8(0..8) {
+                        0 => Some(Spark::new()),
+                        1 | 2 => Some(Smoke::new()),
+                        _ => None,
+                    };
+                    self.set_cell_particle(index, debris);
+                }
+
+                // Push everything moveable outwards, including the new smoke
+                // Particles that can not be pushed are hit by the blast instead
+                let strength = force * (1.0 - distance / (reach as f32 + 1.0));
+                if let Some(p) = self.cells[index].get_particle_mut() {
+                    if distance == 0.0 {
+                        continue;
+                    }
+                    if !p.is_moveable() {
+                        impacts.push((index, strength));
+                        continue;
+                    }
+
+                    let push = Velocity::new(x as f32, y as f32).with_magnitude(strength);
+                    p.set_velocity((p.get_velocity() + push).clamped(MAX_VELOCITY));
+                }
             }
         }
+
+        for (index, strength) in impacts {
+            self.apply_impact(index, strength);
+        }
     }
 
-    // Find the maximum offset to which a particle can either move to or switch to
+    /// Returns true if there is a particle blocking explosions on the line between `from` and `to`, including `to`.
+    fn is_shielded(&self, from: &Offset, to: &Offset) -> bool {
+        from.between(to).iter().skip(1).any(|offset| {
+            match self
+                .get_cell(offset)
+                .and_then(|cell| cell.get_particle().as_ref())
+            {
+                Some(p) => p.blocks_explosions(),
+                None => false,
+            }
+        })
+    }
+
+    // Find the maximum offset to which a particle can either move to or switch to.
+    // Also returns the offset of the obstacle that stopped the particle, if there was any.
     fn find_max_offset(
         &self,
         p_offset: Offset,
         max_offset: Offset,
         particle: &Box<dyn Particle>,
-    ) -> Offset {
+    ) -> (Offset, Option<Offset>) {
         // Get all the offsets between
         let max_pos = p_offset + max_offset;
         let offsets_between = p_offset.between(&max_pos);
@@ -342,7 +951,7 @@ impl Simulation {
             let offset = offsets_between[i];
             // Check bounds
             if !self.is_within(&offset) {
-                return offsets_between[i - 1];
+                return (offsets_between[i - 1], Some(offset));
             }
 
             let index = self.offset_to_index(&offset);
@@ -351,18 +960,26 @@ impl Simulation {
             if let Some(other_p) = cell.get_particle() {
                 // If other_p does not have lower density, then we won't be able to switch
                 if !(particle.can_switch_with(other_p)) {
-                    return offsets_between[i - 1];
+                    return (offsets_between[i - 1], Some(offset));
                 }
             }
         }
 
-        max_pos
+        (max_pos, None)
     }
 
     fn is_within(&self, offset: &Offset) -> bool {
         is_within(self.width, self.height, offset)
     }
 
+    /// Seeds random numbers of the current thread for the cell on the index, if the simulation is deterministic
+    fn reseed(&self, index: usize) -> () {
+        if let Some(seed) = self.seed {
+            let step = self.step_count.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            fastrand::seed(seed ^ step ^ (index as u64).rotate_left(32));
+        }
+    }
+
     fn offset_to_index(&self, offset: &Offset) -> usize {
         offset_to_index(self.width, offset)
     }
@@ -371,23 +988,95 @@ impl Simulation {
         index_to_offset(self.width, index)
     }
 
-    fn get_neighborhood(&self, offset: Offset) -> Neighborhood {
-        let mut neigh: Neighborhood = Neighborhood(vec![vec![None; 3]; 3]);
+    /// Returns a view of the cells around the `offset` up to the `radius`
+    fn get_neighborhood(&self, offset: Offset, radius: u32) -> Neighborhood<'_> {
+        Neighborhood::new(
+            &self.cells,
+            self.width,
+            self.height,
+            offset,
+            radius,
+            self.gravity,
+        )
+    }
+
+    /// Spreads the charge from all sources through the conductors touching them (not diagonally).
+    /// Consumers touching a circuit are charged unless the circuit is shorted.
+    fn propagate_charge(&mut self) -> () {
+        let conductivity = |cell: &Cell| match cell.get_particle() {
+            Some(p) => p.get_conductivity(),
+            None => Conductivity::None,
+        };
 
-        for row_off in -1..=1 {
-            for col_off in -1..=1 {
-                let new_offset = offset + Offset::new(col_off, row_off);
-                let row = (row_off + 1) as usize;
-                let col = (col_off + 1) as usize;
+        let sources: Vec<usize> = self
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| conductivity(cell) == Conductivity::Source)
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut charged: FxHashSet<usize> = FxHashSet::default();
+        let mut visited: FxHashSet<usize> = FxHashSet::default();
+        for source in sources {
+            if visited.contains(&source) {
+                continue;
+            }
 
-                if self.is_within(&new_offset) {
-                    let index = self.offset_to_index(&new_offset);
-                    neigh.0[row][col] = Some(&self.cells[index]);
+            let mut circuit: LinkedList<usize> = LinkedList::new();
+            let mut consumers: FxHashSet<usize> = FxHashSet::default();
+            let mut is_shorted = false;
+            // BFS
+            let mut queue: LinkedList<usize> = LinkedList::new();
+            queue.push_back(source);
+            visited.insert(source);
+            while let Some(cur) = queue.pop_front() {
+                if conductivity(&self.cells[cur]) == Conductivity::Shorting {
+                    is_shorted = true;
                 }
+
+                let cur_offset = self.index_to_offset(cur);
+                for off in [UP, DOWN, LEFT, RIGHT] {
+                    let next = cur_offset + off;
+                    if !self.is_within(&next) {
+                        continue;
+                    }
+                    let next_index = self.offset_to_index(&next);
+
+                    match conductivity(&self.cells[next_index]) {
+                        Conductivity::Source | Conductivity::Conductor | Conductivity::Shorting => {
+                            if visited.insert(next_index) {
+                                queue.push_back(next_index);
+                            }
+                        }
+                        Conductivity::Consumer => {
+                            consumers.insert(next_index);
+                        }
+                        Conductivity::None => {}
+                    }
+                }
+
+                circuit.push_back(cur);
+            }
+
+            charged.extend(circuit);
+            if !is_shorted {
+                charged.extend(consumers);
             }
         }
 
-        neigh
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            if let Some(p) = cell.get_particle_mut() {
+                if p.get_conductivity() == Conductivity::None {
+                    continue;
+                }
+
+                let is_charged = charged.contains(&index);
+                if p.is_charged() != is_charged {
+                    p.set_charged(is_charged);
+                }
+            }
+        }
     }
 
     fn calculate_pressure(&mut self) -> () {
@@ -410,7 +1099,8 @@ impl Simulation {
             })
             .collect();
 
-        if cell_indexes.is_empty() {
+        // Without gravity there is no pressure
+        if cell_indexes.is_empty() || self.gravity.is_zero() {
             return;
         }
 
@@ -424,7 +1114,7 @@ impl Simulation {
 
             let offset = self.index_to_offset(*index);
 
-            let mut area = Area::new();
+            let mut area = Area::new(self.gravity);
             // BFS
             // Init
             let mut queue: LinkedList<Offset> = LinkedList::new();
@@ -468,12 +1158,13 @@ impl Simulation {
                 self.cells[index].set_pressure(depth);
             }
 
+            let up = self.gravity.up();
             let heighest_offsets = area.get_heighest_offsets();
             let teleport_positions: LinkedList<Offset> = area
                 .get_top_edge_offsets()
                 .par_iter()
                 .filter_map(|off| {
-                    let above = **off + UP;
+                    let above = **off + up;
                     let index = self.offset_to_index(off);
                     let pressure = self.cells[index].get_pressure();
                     match self.get_cell(&above) {
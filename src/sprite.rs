@@ -1,7 +1,11 @@
 use std::collections::HashSet;
 
-use image::{open, ImageError};
+use image::{open, ImageError, RgbaImage};
 
+use crate::Rect;
+
+/// Image loaded as pixels in ARGB format.
+#[derive(Clone)]
 pub struct Sprite {
     pub pixels: Vec<u32>,
     pub width: u32,
@@ -11,18 +15,18 @@ pub struct Sprite {
 impl Sprite {
     pub fn load(img_path: &str) -> Result<Sprite, ImageError> {
         // Read the image, possibly return error
-        let img_buffer = open(img_path)?.into_rgb8();
+        let img_buffer = open(img_path)?.into_rgba8();
         // Get dimensions
         let (width, height) = img_buffer.dimensions();
-        // Convert pixels RGB values to u32
+        // Convert pixels RGBA values to u32 in ARGB format
         let pixels: Vec<u32> = img_buffer
             .pixels()
-            .map(|rgb| {
+            .map(|rgba| {
                 let mut color: u32 = 0;
                 // Match colors
-                let [r, g, b] = rgb.0;
-                // Set max opacity
-                color += 0xFF << 24;
+                let [r, g, b, a] = rgba.0;
+                // Set opacity
+                color += (a as u32) << 24;
                 // Set colors
                 color += (r as u32) << 16;
                 color += (g as u32) << 8;
@@ -39,6 +43,104 @@ impl Sprite {
         })
     }
 
+    /// Creates a new sprite with pixels given by the function of their position.
+    fn from_fn(width: u32, height: u32, pixel_fn: impl Fn(u32, u32) -> u32) -> Sprite {
+        let mut pixels = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.push(pixel_fn(x, y));
+            }
+        }
+
+        Sprite {
+            pixels,
+            width,
+            height,
+        }
+    }
+
+    /// Returns color of the pixel on the position. Panics if the position is out of the sprite.
+    pub fn get_pixel(&self, x: u32, y: u32) -> u32 {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// Returns the sprite scaled to the new dimensions using the nearest neighbor.
+    /// Scaling an empty sprite gives a fully transparent one.
+    pub fn scaled(&self, width: u32, height: u32) -> Sprite {
+        if self.width == 0 || self.height == 0 {
+            return Sprite::from_fn(width, height, |_, _| 0);
+        }
+
+        Sprite::from_fn(width, height, |x, y| {
+            self.get_pixel(x * self.width / width, y * self.height / height)
+        })
+    }
+
+    /// Returns the sprite scaled by the factor using the nearest neighbor. Keeps at least one pixel in each dimension.
+    pub fn scaled_by(&self, factor: f32) -> Sprite {
+        let width = ((self.width as f32 * factor).round() as u32).max(1);
+        let height = ((self.height as f32 * factor).round() as u32).max(1);
+
+        self.scaled(width, height)
+    }
+
+    /// Returns the sprite rotated clockwise by 90 degrees `quarter_turns` times. Negative turns rotate counterclockwise.
+    pub fn rotated(&self, quarter_turns: i32) -> Sprite {
+        let (w, h) = (self.width, self.height);
+
+        match quarter_turns.rem_euclid(4) {
+            1 => Sprite::from_fn(h, w, |x, y| self.get_pixel(y, h - 1 - x)),
+            2 => Sprite::from_fn(w, h, |x, y| self.get_pixel(w - 1 - x, h - 1 - y)),
+            3 => Sprite::from_fn(h, w, |x, y| self.get_pixel(w - 1 - y, x)),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the sprite mirrored from left to right.
+    pub fn flipped_horizontally(&self) -> Sprite {
+        Sprite::from_fn(self.width, self.height, |x, y| {
+            self.get_pixel(self.width - 1 - x, y)
+        })
+    }
+
+    /// Returns the sprite mirrored from top to bottom.
+    pub fn flipped_vertically(&self) -> Sprite {
+        Sprite::from_fn(self.width, self.height, |x, y| {
+            self.get_pixel(x, self.height - 1 - y)
+        })
+    }
+
+    /// Returns the part of the sprite inside the rectangle. Parts of the rectangle outside of the sprite are cut off.
+    pub fn cropped(&self, rect: &Rect) -> Sprite {
+        let left = rect.offset.x.clamp(0, self.width as i32) as u32;
+        let top = rect.offset.y.clamp(0, self.height as i32) as u32;
+        let right =
+            (rect.offset.x + rect.width as i32).clamp(left as i32, self.width as i32) as u32;
+        let bottom =
+            (rect.offset.y + rect.height as i32).clamp(top as i32, self.height as i32) as u32;
+
+        Sprite::from_fn(right - left, bottom - top, |x, y| {
+            self.get_pixel(left + x, top + y)
+        })
+    }
+
+    /// Saves the sprite as an image, the format is given by the extension of the path.
+    pub fn save(&self, img_path: &str) -> Result<(), ImageError> {
+        // Convert pixels from ARGB format back to RGBA values
+        let img_buffer = RgbaImage::from_fn(self.width, self.height, |x, y| {
+            let color = self.get_pixel(x, y);
+            let [a, r, g, b] = color.to_be_bytes();
+            image::Rgba([r, g, b, a])
+        });
+
+        img_buffer.save(img_path)
+    }
+
+    /// Returns true if the pixel color is fully transparent
+    pub fn is_transparent(color: u32) -> bool {
+        color >> 24 == 0
+    }
+
     /// Returns a HashSet off all the unique colors in the sprite
     pub fn get_unique_colors(&self) -> HashSet<u32> {
         HashSet::from_iter(self.pixels.clone())
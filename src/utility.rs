@@ -1,6 +1,4 @@
-use std::ops::{Add, Sub};
-
-use rand::{distributions::uniform::SampleUniform, thread_rng, Rng};
+use std::ops::{Add, RangeInclusive, Sub};
 
 use crate::offset::Offset;
 
@@ -19,13 +17,33 @@ pub fn get_offsets_for_square(center: &Offset, size: u32) -> Vec<Offset> {
 }
 
 /// Returns a random value inside the radius with a center in middle.
+/// The randomness comes from `fastrand`, so it is repeatable when `fastrand` is seeded.
 pub fn get_value_around<T>(middle: T, radius: T) -> T
 where
-    T: Add<Output = T> + Sub<Output = T> + SampleUniform + PartialOrd + Copy,
+    T: Add<Output = T> + Sub<Output = T> + RandomInRange + Copy,
 {
-    thread_rng().gen_range((middle - radius)..=(middle + radius))
+    T::random_in((middle - radius)..=(middle + radius))
+}
+
+/// Number types that can be picked at random from a range by `fastrand`.
+pub trait RandomInRange: Sized {
+    fn random_in(range: RangeInclusive<Self>) -> Self;
 }
 
+macro_rules! impl_random_in_range {
+    ($($t:ident),*) => {
+        $(
+            impl RandomInRange for $t {
+                fn random_in(range: RangeInclusive<Self>) -> Self {
+                    fastrand::$t(range)
+                }
+            }
+        )*
+    };
+}
+
+impl_random_in_range!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
 /// Takes a for loop with an else branch. The else branch is executed if the for loop finishes all of its loops.
 ///
 /// # Example:
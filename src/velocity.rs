@@ -0,0 +1,123 @@
+use std::ops;
+
+use crate::offset::Offset;
+use crate::particles::constants::*;
+
+/// Velocity of a particle in cells per update. Both axis can hold sub-cell values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Velocity {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Velocity {
+    pub fn new(x: f32, y: f32) -> Velocity {
+        Velocity { x, y }
+    }
+
+    pub fn zero() -> Velocity {
+        Velocity { x: 0.0, y: 0.0 }
+    }
+
+    /// Returns velocity in the direction of `offset` where each axis is scaled by `speed`.
+    pub fn from_offset(offset: &Offset, speed: f32) -> Velocity {
+        Velocity::new(offset.x as f32, offset.y as f32) * speed
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    /// Returns true if both x and y are 0
+    pub fn is_zero(&self) -> bool {
+        self.x == 0.0 && self.y == 0.0
+    }
+
+    /// Returns velocity with the same direction but with the given magnitude.
+    /// Zero velocity stays zero as it has no direction.
+    pub fn with_magnitude(&self, magnitude: f32) -> Velocity {
+        let current = self.magnitude();
+        if current == 0.0 {
+            return Velocity::zero();
+        }
+
+        *self * (magnitude / current)
+    }
+
+    /// Returns velocity with magnitude of at most `max`.
+    pub fn clamped(&self, max: f32) -> Velocity {
+        if self.magnitude() > max {
+            self.with_magnitude(max)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns velocity decreased by the `portion` of itself, as if by friction.
+    /// Velocities too small to move the particle are stopped completely.
+    pub fn slowed_down(&self, portion: f32) -> Velocity {
+        let velocity = *self * (1.0 - portion);
+
+        if velocity.magnitude() < MIN_VELOCITY {
+            Velocity::zero()
+        } else {
+            velocity
+        }
+    }
+
+    /// Returns velocity in the direction of `offset` after switching with other particle.
+    /// Switching slows the particle down as if by friction.
+    pub fn switching(&self, offset: &Offset) -> Velocity {
+        let speed = DEFAULT_VELOCITY.max(self.magnitude() - SWITCH_SLOWDOWN);
+
+        Velocity::from_offset(offset, speed)
+    }
+
+    /// Returns the whole number of cells to travel during this update.
+    ///
+    /// The velocity is added to `carry`, which accumulates the fractional movement between updates.
+    /// The whole part is taken out of `carry` and returned, the fractional part is kept for the next update.
+    pub fn movement(&self, carry: &mut Velocity) -> Offset {
+        *carry = *carry + *self;
+
+        let x = carry.x.trunc();
+        let y = carry.y.trunc();
+        carry.x -= x;
+        carry.y -= y;
+
+        Offset::new(x as i32, y as i32)
+    }
+}
+
+impl ops::Add for Velocity {
+    type Output = Velocity;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Velocity {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl ops::Sub for Velocity {
+    type Output = Velocity;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Velocity {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl ops::Mul<f32> for Velocity {
+    type Output = Velocity;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Velocity {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
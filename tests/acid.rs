@@ -0,0 +1,56 @@
+use particle_simulation::{
+    particles::{Acid, Rock, Sand},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn acid_dissolves_sand_faster_than_rock() -> () {
+    // Setup - acid poured on a layer of sand and on a layer of rock
+    let mut sand_sim = Simulation::new(10, 10);
+    sand_sim.set_seed(1);
+    let mut rock_sim = Simulation::new(10, 10);
+    rock_sim.set_seed(2);
+    for x in 0..10 {
+        for y in 6..10 {
+            sand_sim.add_particle(&Offset::new(x, y), Sand::new());
+            rock_sim.add_particle(&Offset::new(x, y), Rock::new());
+        }
+        sand_sim.add_particle(&Offset::new(x, 5), Acid::new());
+        rock_sim.add_particle(&Offset::new(x, 5), Acid::new());
+    }
+
+    // Tested
+    for _ in 0..100 {
+        sand_sim.simulate_step();
+        rock_sim.simulate_step();
+    }
+
+    // Check
+    assert!(count(&sand_sim, "Sand") < 40);
+    assert_eq!(count(&rock_sim, "Rock"), 40);
+}
+
+#[test]
+fn acid_is_used_up() -> () {
+    // Setup - a single drop of acid on a pile of sand
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(3);
+    for x in 0..10 {
+        for y in 5..10 {
+            sim.add_particle(&Offset::new(x, y), Sand::new());
+        }
+    }
+    sim.add_particle(&Offset::new(5, 4), Acid::new());
+
+    // Tested
+    for _ in 0..200 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Acid"), 0);
+    assert!(count(&sim, "Sand") < 50);
+}
@@ -0,0 +1,24 @@
+//! Helpers shared by the integration tests.
+
+use particle_simulation::{Offset, Rect, Simulation};
+
+/// Area of the whole simulation
+#[allow(dead_code)]
+pub fn whole(sim: &Simulation) -> Rect {
+    Rect::new(Offset::zero(), sim.width() as u32, sim.height() as u32)
+}
+
+/// Number of particles with the name in the whole simulation
+#[allow(dead_code)]
+pub fn count(sim: &Simulation, name: &str) -> usize {
+    sim.count_by_name(&whole(sim))
+        .get(name)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Number of all particles in the simulation
+#[allow(dead_code)]
+pub fn count_all(sim: &Simulation) -> usize {
+    sim.cells_iter().filter(|cell| !cell.is_empty()).count()
+}
@@ -0,0 +1,44 @@
+use particle_simulation::{
+    particles::{Concrete, Water},
+    Offset, Simulation,
+};
+
+/// Returns the number of concrete particles that are set (can no longer move)
+fn count_set(sim: &Simulation) -> usize {
+    sim.cells_iter()
+        .filter(|cell| match cell.get_particle() {
+            Some(p) => p.get_name() == "Concrete" && !p.is_moveable(),
+            None => false,
+        })
+        .count()
+}
+
+#[test]
+fn concrete_sets_faster_when_dry() -> () {
+    // Setup - concrete poured on the ground and into water
+    let mut dry = Simulation::new(10, 10);
+    dry.set_seed(1);
+    let mut wet = Simulation::new(10, 10);
+    wet.set_seed(2);
+    for x in 0..10 {
+        for y in 5..10 {
+            wet.add_particle(&Offset::new(x, y), Water::new());
+        }
+    }
+    for x in 3..7 {
+        for y in 0..2 {
+            dry.add_particle(&Offset::new(x, y), Concrete::new());
+            wet.add_particle(&Offset::new(x, y), Concrete::new());
+        }
+    }
+
+    // Tested
+    for _ in 0..400 {
+        dry.simulate_step();
+        wet.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count_set(&dry), 8);
+    assert_eq!(count_set(&wet), 0);
+}
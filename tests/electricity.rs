@@ -0,0 +1,80 @@
+use particle_simulation::{
+    particles::{Battery, Burnability, Igniter, Lamp, Metal, Water, Wood},
+    Offset, Simulation,
+};
+
+fn is_charged(sim: &Simulation, offset: &Offset) -> bool {
+    match sim.get_particle(offset) {
+        Some(p) => p.is_charged(),
+        None => false,
+    }
+}
+
+#[test]
+fn lamp_lights_when_connected() -> () {
+    // Setup - battery connected to a lamp by a wire
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(1);
+    sim.add_particle(&Offset::new(1, 5), Battery::new());
+    for x in 2..6 {
+        sim.add_particle(&Offset::new(x, 5), Metal::new());
+    }
+    let lamp = Offset::new(6, 5);
+    sim.add_particle(&lamp, Lamp::new());
+
+    // Tested
+    sim.simulate_step();
+    let lit = is_charged(&sim, &lamp);
+    // Cut the wire
+    sim.remove_particle(&Offset::new(3, 5));
+    sim.simulate_step();
+    let lit_after_cut = is_charged(&sim, &lamp);
+
+    // Check
+    assert!(lit);
+    assert!(!lit_after_cut);
+}
+
+#[test]
+fn water_shorts_circuit() -> () {
+    // Setup - same circuit as above lying in water
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(2);
+    for x in 0..10 {
+        sim.add_particle(&Offset::new(x, 9), Water::new());
+    }
+    sim.add_particle(&Offset::new(1, 8), Battery::new());
+    for x in 2..6 {
+        sim.add_particle(&Offset::new(x, 8), Metal::new());
+    }
+    let lamp = Offset::new(6, 8);
+    sim.add_particle(&lamp, Lamp::new());
+
+    // Tested
+    sim.simulate_step();
+
+    // Check - the wire is charged but the lamp is not
+    assert!(is_charged(&sim, &Offset::new(4, 8)));
+    assert!(!is_charged(&sim, &lamp));
+}
+
+#[test]
+fn igniter_sets_wood_on_fire() -> () {
+    // Setup - battery powering an igniter next to a block of wood
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(3);
+    sim.add_particle(&Offset::new(1, 5), Battery::new());
+    sim.add_particle(&Offset::new(2, 5), Igniter::new());
+    for x in 3..6 {
+        sim.add_particle(&Offset::new(x, 5), Wood::new());
+    }
+
+    // Tested
+    for _ in 0..20 {
+        sim.simulate_step();
+    }
+
+    // Check - the wood next to the igniter is burning
+    let wood = sim.get_particle(&Offset::new(3, 5)).as_ref().unwrap();
+    assert!(matches!(wood.get_burnability(), Burnability::IsBurning(_)));
+}
@@ -0,0 +1,65 @@
+use particle_simulation::{
+    particles::{Rock, Sand, Spark, Tnt, Wood},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+/// Explosion radius of TNT
+const TNT_RADIUS: usize = 6;
+
+#[test]
+fn tnt_clears_crater_but_not_rock() -> () {
+    // Setup - TNT buried in sand with a rock next to it and a spark to light it up
+    let mut sim = Simulation::new(30, 30);
+    sim.set_seed(1);
+    for x in 0..30 {
+        for y in 15..30 {
+            sim.add_particle(&Offset::new(x, y), Sand::new());
+        }
+    }
+    let center = Offset::new(15, 20);
+    sim.change_particle(&center, Tnt::new());
+    sim.change_particle(&Offset::new(17, 20), Rock::new());
+    sim.change_particle(&Offset::new(15, 19), Spark::new());
+    let sand = count(&sim, "Sand");
+
+    // Tested - enough steps to catch fire and burn the fuse
+    for _ in 0..200 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "TNT"), 0);
+    assert_eq!(count(&sim, "Rock"), 1);
+    // At least the square inscribed in the crater is destroyed, even with the part shielded by the rock
+    let crater = 2 * TNT_RADIUS * TNT_RADIUS;
+    assert!(count(&sim, "Sand") < sand - crater);
+}
+
+#[test]
+fn explosion_sets_off_other_charges() -> () {
+    // Setup - two charges in the air, the second one in the crater of the first one
+    // Wood is out of reach of the first charge, but inside the crater of the second one
+    let mut sim = Simulation::new(40, 40);
+    sim.set_seed(4);
+    sim.change_particle(&Offset::new(10, 20), Tnt::new());
+    sim.change_particle(&Offset::new(15, 20), Tnt::new());
+    for y in 19..=21 {
+        sim.change_particle(&Offset::new(20, y), Wood::new());
+    }
+    sim.change_particle(&Offset::new(10, 19), Spark::new());
+
+    // Tested - burn the fuse of the first charge until it explodes
+    let mut steps = 0;
+    while count(&sim, "TNT") == 2 && steps < 200 {
+        sim.simulate_step();
+        steps += 1;
+    }
+    sim.simulate_step();
+
+    // Check - the second charge went off as well and destroyed the wood
+    assert_eq!(count(&sim, "TNT"), 0);
+    assert_eq!(count(&sim, "Wood"), 0);
+}
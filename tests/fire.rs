@@ -0,0 +1,75 @@
+use particle_simulation::{
+    particles::{Burnability, Fire, Spark, Wood},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn fire_dies_quickly() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 40);
+    sim.set_seed(1);
+    sim.add_particle(&Offset::new(5, 35), Fire::new());
+
+    // Tested
+    for _ in 0..40 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Fire"), 0);
+}
+
+#[test]
+fn burning_wood_emits_fire() -> () {
+    // Setup - block of wood lit by a spark
+    let mut sim = Simulation::new(20, 20);
+    sim.set_seed(2);
+    for x in 5..15 {
+        for y in 15..20 {
+            sim.add_particle(&Offset::new(x, y), Wood::new());
+        }
+    }
+    sim.add_particle(&Offset::new(10, 14), Spark::new());
+
+    // Tested
+    let mut max_fire = 0;
+    for _ in 0..100 {
+        sim.simulate_step();
+        max_fire = max_fire.max(count(&sim, "Fire"));
+    }
+
+    // Check - flames were emitted and the wood keeps its shape while burning
+    assert!(max_fire > 0);
+    assert!(count(&sim, "Wood") > 0);
+}
+
+#[test]
+fn fire_ignites_wood() -> () {
+    // Setup - fire right under a wooden plank
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(3);
+    for x in 0..10 {
+        sim.add_particle(&Offset::new(x, 3), Wood::new());
+    }
+    sim.add_particle(&Offset::new(5, 4), Fire::new());
+
+    // Tested
+    for _ in 0..10 {
+        sim.simulate_step();
+    }
+
+    // Check
+    let burning = sim
+        .cells_iter()
+        .filter(|cell| match cell.get_particle() {
+            Some(p) => {
+                matches!(p.get_burnability(), Burnability::IsBurning(_)) && p.get_name() == "Wood"
+            }
+            None => false,
+        })
+        .count();
+    assert!(burning > 0);
+}
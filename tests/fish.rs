@@ -0,0 +1,82 @@
+use particle_simulation::{
+    particles::{Fish, SaltWater, Sand, Water},
+    Offset, Simulation,
+};
+
+/// Returns the row and density of the only fish in the simulation
+fn find_fish(sim: &Simulation) -> (usize, u8) {
+    sim.cells_iter()
+        .enumerate()
+        .find_map(|(i, cell)| match cell.get_particle() {
+            Some(p) if p.get_name() == "Fish" => Some((i / sim.width(), p.get_density())),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn fish_stays_in_water() -> () {
+    // Setup - a fish in a pool of water
+    let mut sim = Simulation::new(20, 20);
+    sim.set_seed(1);
+    for x in 0..20 {
+        for y in 10..20 {
+            sim.add_particle(&Offset::new(x, y), Water::new());
+        }
+    }
+    sim.change_particle(&Offset::new(10, 15), Fish::new());
+
+    // Tested
+    for _ in 0..300 {
+        sim.simulate_step();
+    }
+
+    // Check - fish is still alive and under the surface
+    let (row, density) = find_fish(&sim);
+    assert!(row >= 10);
+    assert_eq!(density, 128);
+}
+
+#[test]
+fn fish_dies_on_land() -> () {
+    // Setup - a fish on a sand floor
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(2);
+    for x in 0..10 {
+        sim.add_particle(&Offset::new(x, 9), Sand::new());
+    }
+    sim.add_particle(&Offset::new(5, 5), Fish::new());
+
+    // Tested
+    for _ in 0..200 {
+        sim.simulate_step();
+    }
+
+    // Check - dead fish is heavier than water
+    let (row, density) = find_fish(&sim);
+    assert_eq!(row, 8);
+    assert_eq!(density, 140);
+}
+
+#[test]
+fn fish_lives_in_salt_water() -> () {
+    // Setup - a fish in a pool of salt water
+    let mut sim = Simulation::new(20, 20);
+    sim.set_seed(5);
+    for x in 0..20 {
+        for y in 10..20 {
+            sim.add_particle(&Offset::new(x, y), SaltWater::new());
+        }
+    }
+    sim.change_particle(&Offset::new(10, 15), Fish::new());
+
+    // Tested
+    for _ in 0..300 {
+        sim.simulate_step();
+    }
+
+    // Check - fish is still alive and under the surface
+    let (row, density) = find_fish(&sim);
+    assert!(row >= 10);
+    assert_eq!(density, 128);
+}
@@ -0,0 +1,57 @@
+use particle_simulation::{
+    particles::{Fly, Lava, Mud, Rock},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn fly_eats_and_reproduces() -> () {
+    // Setup - a fly above a field of mud
+    let mut sim = Simulation::new(20, 20);
+    sim.set_seed(1);
+    for x in 0..20 {
+        for y in 15..20 {
+            sim.add_particle(&Offset::new(x, y), Mud::new());
+        }
+    }
+    sim.add_particle(&Offset::new(10, 10), Fly::new());
+
+    // Tested
+    for _ in 0..300 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert!(count(&sim, "Mud") < 100);
+    assert!(count(&sim, "Fly") > 1);
+}
+
+#[test]
+fn fly_burns_only_next_to_fire() -> () {
+    // Setup - a fly walled in by rock, lava resting two cells away within its sight
+    let mut sim = Simulation::new(7, 5);
+    sim.set_seed(3);
+    for x in 0..7 {
+        sim.add_particle(&Offset::new(x, 4), Rock::new());
+    }
+    for x in 1..=3 {
+        for y in 1..=3 {
+            if x != 2 || y != 2 {
+                sim.add_particle(&Offset::new(x, y), Rock::new());
+            }
+        }
+    }
+    sim.add_particle(&Offset::new(2, 2), Fly::new());
+    sim.add_particle(&Offset::new(4, 3), Lava::new());
+    sim.add_particle(&Offset::new(5, 3), Rock::new());
+
+    // Tested
+    for _ in 0..100 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Fly"), 1);
+}
@@ -0,0 +1,56 @@
+use particle_simulation::{Falloff, ForceField, Offset, Rect, Velocity};
+
+#[test]
+fn wind_only_inside_area() -> () {
+    // Setup
+    let area = Rect::new(Offset::new(0, 0), 10, 10);
+    let wind = ForceField::wind(area, Velocity::new(2.0, 0.0), 0.5);
+
+    // Check
+    assert_eq!(wind.force_at(&Offset::new(3, 3)), Velocity::new(0.5, 0.0));
+    assert_eq!(wind.force_at(&Offset::new(10, 3)), Velocity::zero());
+}
+
+#[test]
+fn attractor_and_repulsor() -> () {
+    // Setup
+    let center = Offset::new(10, 10);
+    let attractor = ForceField::attractor(center, 10.0, 1.0).with_falloff(Falloff::None);
+    let repulsor = ForceField::repulsor(center, 10.0, 1.0).with_falloff(Falloff::None);
+
+    // Check
+    assert_eq!(
+        attractor.force_at(&Offset::new(15, 10)),
+        Velocity::new(-1.0, 0.0)
+    );
+    assert_eq!(
+        repulsor.force_at(&Offset::new(15, 10)),
+        Velocity::new(1.0, 0.0)
+    );
+    // Out of the radius
+    assert_eq!(attractor.force_at(&Offset::new(25, 10)), Velocity::zero());
+}
+
+#[test]
+fn vortex_spins_clockwise() -> () {
+    // Setup
+    let vortex = ForceField::vortex(Offset::new(10, 10), 10.0, 1.0).with_falloff(Falloff::None);
+
+    // Right of the center goes down, above the center goes right
+    assert_eq!(
+        vortex.force_at(&Offset::new(15, 10)),
+        Velocity::new(0.0, 1.0)
+    );
+    assert_eq!(
+        vortex.force_at(&Offset::new(10, 5)),
+        Velocity::new(1.0, 0.0)
+    );
+}
+
+#[test]
+fn falloff() -> () {
+    assert_eq!(Falloff::None.multiplier(5.0, 10.0), 1.0);
+    assert_eq!(Falloff::Linear.multiplier(5.0, 10.0), 0.5);
+    assert_eq!(Falloff::Quadratic.multiplier(5.0, 10.0), 0.25);
+    assert_eq!(Falloff::Linear.multiplier(11.0, 10.0), 0.0);
+}
@@ -0,0 +1,135 @@
+//! Random sequences of edits and steps on small simulations, checking that nothing panics and the invariants hold.
+
+use particle_simulation::{particles::PARTICLE_TYPES, Gravity, Offset, Simulation};
+use proptest::prelude::*;
+use rustc_hash::FxHashMap;
+
+mod common;
+use common::whole;
+
+/// Largest width and height of the fuzzed simulations
+const MAX_SIZE: usize = 12;
+
+#[derive(Clone, Debug)]
+enum Edit {
+    Add { x: i32, y: i32, kind: usize },
+    Remove { x: i32, y: i32 },
+    Change { x: i32, y: i32, kind: usize },
+    Step(u8),
+    Gravity { angle: f32, strength: f32 },
+}
+
+/// Particles that do not react with each other and are never created or destroyed on their own
+const INERT: [&str; 2] = ["Sand", "Rock"];
+
+/// Edits with offsets that may be just outside of the simulation, adding particles of the `kinds` (indexes to `PARTICLE_TYPES`)
+fn edit_strategy(kinds: Vec<usize>) -> impl Strategy<Value = Edit> {
+    let coord = -2..MAX_SIZE as i32 + 2;
+    let kind = prop::sample::select(kinds);
+
+    prop_oneof![
+        4 => (coord.clone(), coord.clone(), kind.clone()).prop_map(|(x, y, kind)| Edit::Add { x, y, kind }),
+        1 => (coord.clone(), coord.clone()).prop_map(|(x, y)| Edit::Remove { x, y }),
+        1 => (coord.clone(), coord, kind).prop_map(|(x, y, kind)| Edit::Change { x, y, kind }),
+        3 => (1..6u8).prop_map(Edit::Step),
+        1 => (-180.0..180.0f32, 0.0..0.5f32).prop_map(|(angle, strength)| Edit::Gravity { angle, strength }),
+    ]
+}
+
+fn apply(sim: &mut Simulation, edit: &Edit) -> () {
+    match *edit {
+        Edit::Add { x, y, kind } => {
+            sim.add_particle(&Offset::new(x, y), (PARTICLE_TYPES[kind].new)());
+        }
+        Edit::Remove { x, y } => {
+            sim.remove_particle(&Offset::new(x, y));
+        }
+        Edit::Change { x, y, kind } => {
+            sim.change_particle(&Offset::new(x, y), (PARTICLE_TYPES[kind].new)());
+        }
+        Edit::Step(steps) => {
+            for _ in 0..steps {
+                sim.simulate_step();
+            }
+        }
+        Edit::Gravity { angle, strength } => {
+            sim.set_gravity(Gravity::from_angle(angle, strength));
+        }
+    }
+}
+
+fn all_kinds() -> Vec<usize> {
+    (0..PARTICLE_TYPES.len()).collect()
+}
+
+fn inert_kinds() -> Vec<usize> {
+    PARTICLE_TYPES
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| INERT.contains(&t.name))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Number of particles of each name in the whole simulation
+fn counts(sim: &Simulation) -> FxHashMap<String, usize> {
+    sim.count_by_name(&whole(sim))
+}
+
+/// Pressure is the depth in a liquid, so it can not be negative or deeper than the simulation
+fn pressure_in_range(sim: &Simulation) -> bool {
+    let max_depth = (sim.width() + sim.height() - 2) as i32;
+    sim.cells_iter()
+        .all(|cell| (0..=max_depth).contains(&cell.get_pressure()))
+}
+
+// Number of cases can be raised with the `PROPTEST_CASES` environment variable
+proptest! {
+    #[test]
+    fn random_edits_and_steps(
+        width in 1..=MAX_SIZE,
+        height in 1..=MAX_SIZE,
+        seed in any::<u64>(),
+        edits in prop::collection::vec(edit_strategy(all_kinds()), 1..40),
+    ) {
+        // Setup
+        let mut sim = Simulation::new(width, height);
+        // Seeded, so failing cases can be shrunk and replayed
+        sim.set_seed(seed);
+
+        for edit in &edits {
+            // Tested
+            apply(&mut sim, edit);
+
+            // Check - after every edit, not only after steps
+            let count = sim.cells_iter().filter(|cell| !cell.is_empty()).count();
+            prop_assert_eq!(sim.cells_iter().count(), width * height);
+            prop_assert_eq!(sim.info().particle_count as usize, count, "after {:?}", edit);
+            prop_assert!(pressure_in_range(&sim), "after {:?}", edit);
+        }
+    }
+
+    #[test]
+    fn inert_particles_are_conserved(
+        width in 1..=MAX_SIZE,
+        height in 1..=MAX_SIZE,
+        seed in any::<u64>(),
+        edits in prop::collection::vec(edit_strategy(inert_kinds()), 1..40),
+    ) {
+        // Setup
+        let mut sim = Simulation::new(width, height);
+        sim.set_seed(seed);
+
+        for edit in &edits {
+            let before = counts(&sim);
+
+            // Tested
+            apply(&mut sim, edit);
+
+            // Check - only edits may add or remove particles, steps and gravity changes just move them
+            if let Edit::Step(_) | Edit::Gravity { .. } = edit {
+                prop_assert_eq!(counts(&sim), before, "after {:?}", edit);
+            }
+        }
+    }
+}
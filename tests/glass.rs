@@ -0,0 +1,49 @@
+use particle_simulation::{
+    particles::{Battery, Glass, Igniter, Metal, Sand},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn sand_melts_into_glass() -> () {
+    // Setup - sand lying on a row of powered igniters
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(1);
+    sim.add_particle(&Offset::new(0, 9), Battery::new());
+    for x in 1..10 {
+        sim.add_particle(&Offset::new(x, 9), Metal::new());
+        sim.add_particle(&Offset::new(x, 8), Igniter::new());
+        sim.add_particle(&Offset::new(x, 7), Sand::new());
+    }
+
+    // Tested
+    for _ in 0..100 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Sand"), 0);
+    assert_eq!(count(&sim, "Glass"), 9);
+}
+
+#[test]
+fn glass_shatters_on_impact() -> () {
+    // Setup - sand falling from high on a glass pane
+    let mut sim = Simulation::new(10, 40);
+    sim.set_seed(2);
+    for x in 0..10 {
+        sim.add_particle(&Offset::new(x, 39), Glass::new());
+    }
+    sim.add_particle(&Offset::new(5, 0), Sand::new());
+
+    // Tested
+    for _ in 0..100 {
+        sim.simulate_step();
+    }
+
+    // Check - the hit glass turned into sand
+    assert_eq!(count(&sim, "Glass"), 9);
+    assert_eq!(count(&sim, "Sand"), 2);
+}
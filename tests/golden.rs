@@ -0,0 +1,133 @@
+//! Golden image tests. Each scene is simulated with a fixed seed, rendered with the default palette
+//! and compared to the image in `tests/golden`. Run with `UPDATE_GOLDEN=1` to regenerate the images.
+
+use particle_simulation::{
+    particles::{Fire, Rock, Sand, Water, Wood},
+    Offset, Palette, Rect, Simulation, Sprite,
+};
+
+const SIZE: usize = 64;
+const SEED: u64 = 2024;
+/// Portion of pixels that may differ from the golden image
+const TOLERANCE: f32 = 0.01;
+
+/// Renders the simulation and compares it to the golden image with the name, or overwrites it if `UPDATE_GOLDEN` is set
+fn check_golden(name: &str, sim: &Simulation) -> () {
+    let path = format!("tests/golden/{}.png", name);
+    let rendered = sim.to_sprite(&Palette::default());
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        rendered.save(&path).unwrap();
+        return;
+    }
+
+    let golden = Sprite::load(&path).unwrap_or_else(|_| {
+        panic!(
+            "Missing golden image {}, run with UPDATE_GOLDEN=1 to create it",
+            path
+        )
+    });
+    assert_eq!(
+        (golden.width, golden.height),
+        (rendered.width, rendered.height)
+    );
+    let different = golden
+        .pixels
+        .iter()
+        .zip(&rendered.pixels)
+        .filter(|(a, b)| a != b)
+        .count();
+    let allowed = (golden.pixels.len() as f32 * TOLERANCE) as usize;
+    assert!(
+        different <= allowed,
+        "{} pixels differ from {}, at most {} may",
+        different,
+        path,
+        allowed
+    );
+}
+
+fn fill(
+    sim: &mut Simulation,
+    rect: Rect,
+    new_fn: fn() -> Box<dyn particle_simulation::particles::Particle>,
+) -> () {
+    for off in rect.offsets() {
+        sim.add_particle(&off, new_fn());
+    }
+}
+
+fn seeded_simulation() -> Simulation {
+    let mut sim = Simulation::new(SIZE, SIZE);
+    sim.set_seed(SEED);
+
+    sim
+}
+
+fn sand_pile() -> Simulation {
+    let mut sim = seeded_simulation();
+    // Pour sand from a single point
+    for _ in 0..150 {
+        sim.add_particle(&Offset::new(SIZE as i32 / 2, 0), Sand::new());
+        sim.simulate_step();
+    }
+    for _ in 0..150 {
+        sim.simulate_step();
+    }
+
+    sim
+}
+
+fn water_u_tube() -> Simulation {
+    let mut sim = seeded_simulation();
+    // U shaped tube with walls of rock, water poured into the left arm
+    fill(&mut sim, Rect::new(Offset::new(10, 10), 2, 50), Rock::new);
+    fill(&mut sim, Rect::new(Offset::new(52, 10), 2, 50), Rock::new);
+    fill(&mut sim, Rect::new(Offset::new(12, 58), 40, 2), Rock::new);
+    fill(&mut sim, Rect::new(Offset::new(22, 10), 20, 44), Rock::new);
+    fill(&mut sim, Rect::new(Offset::new(12, 10), 10, 30), Water::new);
+    for _ in 0..400 {
+        sim.simulate_step();
+    }
+
+    sim
+}
+
+fn burning_wood() -> Simulation {
+    let mut sim = seeded_simulation();
+    // Wood standing in a pool of water, set on fire from above
+    fill(&mut sim, Rect::new(Offset::new(0, 60), 64, 4), Rock::new);
+    fill(&mut sim, Rect::new(Offset::new(0, 50), 64, 10), Water::new);
+    fill(&mut sim, Rect::new(Offset::new(24, 30), 16, 20), Wood::new);
+    fill(&mut sim, Rect::new(Offset::new(28, 28), 8, 2), Fire::new);
+    for _ in 0..100 {
+        sim.simulate_step();
+    }
+
+    sim
+}
+
+#[test]
+fn golden_sand_pile() -> () {
+    check_golden("sand_pile", &sand_pile());
+}
+
+#[test]
+fn golden_water_u_tube() -> () {
+    check_golden("water_u_tube", &water_u_tube());
+}
+
+#[test]
+fn golden_burning_wood() -> () {
+    check_golden("burning_wood", &burning_wood());
+}
+
+#[test]
+fn seeded_simulation_is_deterministic() -> () {
+    // Setup & Tested
+    let first = burning_wood().to_sprite(&Palette::default());
+    let second = burning_wood().to_sprite(&Palette::default());
+
+    // Check
+    assert!(first.pixels == second.pixels);
+}
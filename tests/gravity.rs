@@ -0,0 +1,46 @@
+use particle_simulation::{Gravity, Offset, Velocity};
+
+#[test]
+fn default_directions() -> () {
+    let gravity = Gravity::default();
+
+    assert_eq!(gravity.down(), Offset::new(0, 1));
+    assert_eq!(gravity.up(), Offset::new(0, -1));
+    assert_eq!(gravity.left(), Offset::new(-1, 0));
+    assert_eq!(gravity.right(), Offset::new(1, 0));
+    assert_eq!(gravity.down_left(), Offset::new(-1, 1));
+    assert_eq!(gravity.down_right(), Offset::new(1, 1));
+}
+
+#[test]
+fn rotated_directions() -> () {
+    // Pulling to the left of the grid
+    let gravity = Gravity::from_angle(90.0, 0.1);
+
+    assert_eq!(gravity.down(), Offset::new(-1, 0));
+    assert_eq!(gravity.up(), Offset::new(1, 0));
+    assert_eq!(gravity.left(), Offset::new(0, -1));
+    assert_eq!(gravity.down_right(), Offset::new(-1, 1));
+}
+
+#[test]
+fn zero_g() -> () {
+    let gravity = Gravity::from_angle(45.0, 0.0);
+
+    assert!(gravity.is_zero());
+    assert_eq!(gravity.down(), Offset::zero());
+    assert!(!gravity.is_down(&Offset::zero()));
+}
+
+#[test]
+fn fall_accelerates_along_gravity() -> () {
+    // Setup
+    let gravity = Gravity::new(Velocity::new(1.0, 0.0), 0.5);
+
+    // Tested method
+    let velocity = gravity.fall(&Velocity::new(2.0, 1.0));
+
+    // Check
+    assert_eq!(velocity.x, 2.5);
+    assert!(velocity.y < 1.0 && velocity.y > 0.9);
+}
@@ -0,0 +1,77 @@
+use particle_simulation::{
+    particles::{Ice, Lava, Rock, Snow, Water},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn water_freezes_next_to_ice() -> () {
+    // Setup - a thin layer of water on top of ice
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(1);
+    for x in 0..10 {
+        sim.add_particle(&Offset::new(x, 9), Ice::new());
+        sim.add_particle(&Offset::new(x, 8), Water::new());
+    }
+
+    // Tested
+    for _ in 0..200 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Water"), 0);
+    assert_eq!(count(&sim, "Ice"), 20);
+}
+
+#[test]
+fn ice_melts_next_to_lava() -> () {
+    // Setup - a wall of ice holding back a pool of lava
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(2);
+    for y in 0..10 {
+        sim.add_particle(&Offset::new(5, y), Ice::new());
+        for x in 0..5 {
+            if y >= 6 {
+                sim.add_particle(&Offset::new(x, y), Lava::new());
+            }
+        }
+    }
+
+    // Tested
+    for _ in 0..100 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert!(count(&sim, "Ice") < 10);
+}
+
+#[test]
+fn snow_melts_only_next_to_heat() -> () {
+    // Setup - on a rock floor, one snow touches lava and the other is separated by a rock
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(1);
+    for x in 0..10 {
+        sim.add_particle(&Offset::new(x, 9), Rock::new());
+    }
+    sim.add_particle(&Offset::new(0, 8), Snow::new());
+    sim.add_particle(&Offset::new(1, 8), Lava::new());
+    sim.add_particle(&Offset::new(2, 8), Rock::new());
+    sim.add_particle(&Offset::new(3, 8), Snow::new());
+    sim.add_particle(&Offset::new(4, 8), Rock::new());
+
+    // Tested
+    for _ in 0..100 {
+        sim.simulate_step();
+    }
+
+    // Check - snow looks 3 cells up for compaction, but melts only from the adjacent lava
+    assert_eq!(count(&sim, "Snow"), 1);
+    assert!(sim
+        .get_particle(&Offset::new(3, 8))
+        .as_ref()
+        .is_some_and(|p| p.get_name() == "Snow"));
+}
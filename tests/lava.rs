@@ -0,0 +1,36 @@
+use particle_simulation::{
+    particles::{Lava, Water},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn lava_cools_in_water() -> () {
+    // Setup - a blob of lava dropped into a pool of water
+    let mut sim = Simulation::new(20, 20);
+    sim.set_seed(1);
+    for x in 0..20 {
+        for y in 12..20 {
+            sim.add_particle(&Offset::new(x, y), Water::new());
+        }
+    }
+    for x in 8..12 {
+        for y in 0..3 {
+            sim.add_particle(&Offset::new(x, y), Lava::new());
+        }
+    }
+    let water = count(&sim, "Water");
+
+    // Tested
+    for _ in 0..300 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Lava"), 0);
+    assert!(count(&sim, "Rock") > 0);
+    // Some of the water boiled away
+    assert!(count(&sim, "Water") < water);
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use particle_simulation::{
+    particles::{Rock, SaltWater, Sand, Water, PARTICLE_TYPES},
+    Offset, Palette, Simulation,
+};
+
+#[test]
+fn canonical_colors_are_unique() -> () {
+    let colors: HashSet<u32> = PARTICLE_TYPES.iter().map(|t| t.color).collect();
+
+    assert_eq!(colors.len(), PARTICLE_TYPES.len());
+}
+
+#[test]
+fn level_round_trip() -> () {
+    // Setup
+    let mut sim = Simulation::new(20, 10);
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(5, 3), Water::new());
+    sim.add_particle(&Offset::new(19, 9), Rock::new());
+    sim.add_particle(&Offset::new(10, 9), SaltWater::new());
+    let palette = Palette::default();
+    let path = std::env::temp_dir().join("particle_simulation_level_round_trip.png");
+    let path = path.to_str().unwrap();
+
+    // Tested
+    sim.export_level(path, &palette).unwrap();
+    let loaded = Simulation::import_level(path, &palette).unwrap();
+
+    // Check
+    assert_eq!(loaded.width(), sim.width());
+    assert_eq!(loaded.height(), sim.height());
+    let names = |sim: &Simulation| -> Vec<Option<String>> {
+        sim.cells_iter()
+            .map(|cell| {
+                cell.get_particle()
+                    .as_ref()
+                    .map(|p| p.get_name().to_string())
+            })
+            .collect()
+    };
+    assert_eq!(names(&loaded), names(&sim));
+}
@@ -0,0 +1,59 @@
+use particle_simulation::{particles::Sand, Cell, Gravity, Neighborhood, Offset};
+
+/// Creates a 5x5 grid with a particle in the top left corner
+fn grid() -> Vec<Cell> {
+    let mut cells = vec![Cell::empty(); 25];
+    cells[0].set_particle(Sand::new());
+
+    cells
+}
+
+#[test]
+fn on_relative_inside_radius() -> () {
+    // Setup
+    let cells = grid();
+    let neigh = Neighborhood::new(&cells, 5, 5, Offset::new(2, 2), 2, Gravity::default());
+
+    // Check
+    assert!(neigh
+        .on_relative(&Offset::new(-2, -2))
+        .is_some_and(|c| !c.is_empty()));
+    assert!(neigh
+        .on_relative(&Offset::new(2, 2))
+        .is_some_and(|c| c.is_empty()));
+    assert_eq!(neigh.iter().count(), 25);
+}
+
+#[test]
+fn on_relative_outside() -> () {
+    // Setup
+    let cells = grid();
+    let neigh = Neighborhood::new(&cells, 5, 5, Offset::new(1, 1), 1, Gravity::default());
+
+    // Outside of the radius
+    assert!(neigh.on_relative(&Offset::new(2, 0)).is_none());
+    // Inside the radius
+    assert!(neigh.up_left().is_some_and(|c| !c.is_empty()));
+
+    // Outside of the grid
+    let neigh = Neighborhood::new(&cells, 5, 5, Offset::new(0, 0), 1, Gravity::default());
+    assert!(neigh.up().is_none());
+    assert_eq!(neigh.iter().filter(|opt| opt.is_some()).count(), 4);
+}
+
+#[test]
+fn adjacent_ignores_radius() -> () {
+    // Setup
+    let cells = grid();
+    let neigh = Neighborhood::new(&cells, 5, 5, Offset::new(2, 2), 2, Gravity::default());
+
+    // Check
+    assert_eq!(neigh.adjacent().count(), 9);
+    // The particle in the corner is in the radius, but not adjacent
+    assert!(neigh
+        .adjacent()
+        .all(|opt| opt.is_some_and(|c| c.is_empty())));
+    assert!(neigh
+        .adjacent_relative()
+        .all(|(off, _)| off.x.abs() <= 1 && off.y.abs() <= 1));
+}
@@ -68,3 +68,25 @@ fn between_right_down() -> () {
         ]
     );
 }
+
+#[test]
+fn between_not_straight() -> () {
+    // Setup
+    let og_pos = Offset::new(0, 0);
+    let max_pos = Offset::new(4, 2); // 4 right, 2 down
+
+    // Tested method
+    let between = og_pos.between(&max_pos);
+
+    // Check
+    assert_eq!(
+        between,
+        vec![
+            og_pos,
+            Offset::new(1, 1),
+            Offset::new(2, 1),
+            Offset::new(3, 2),
+            max_pos
+        ]
+    );
+}
@@ -0,0 +1,79 @@
+use particle_simulation::{Offset, Palette, PaletteError, Simulation, Sprite};
+
+#[test]
+fn parse_palette() -> () {
+    // Setup
+    let text = "// Comment\n#FFFFFF Sand\n\n#3F7FC0 Salt Water\n";
+
+    // Tested
+    let palette = Palette::parse(text).unwrap();
+
+    // Check
+    assert_eq!(palette.nearest(0xFFFFFF).unwrap().name, "Sand");
+    assert_eq!(palette.nearest(0x3F7FC0).unwrap().name, "Salt Water");
+}
+
+#[test]
+fn parse_invalid_palette() -> () {
+    assert!(matches!(
+        Palette::parse("#FFFFFF Unobtainium"),
+        Err(PaletteError::UnknownParticle(_))
+    ));
+    assert!(matches!(
+        Palette::parse("#FFFFFF Sand\nFFF Water"),
+        Err(PaletteError::InvalidLine(2))
+    ));
+}
+
+#[test]
+fn nearest_color() -> () {
+    // Setup
+    let palette = Palette::load("./assets/palette.txt").unwrap();
+
+    // Tested
+    let light = palette.nearest(0xFFF0F0F0).unwrap();
+    let dark = palette.nearest(0xFF102080).unwrap();
+
+    // Check
+    assert_eq!(light.name, "Sand");
+    assert_eq!(dark.name, "Water");
+}
+
+#[test]
+fn insert_sprite_with_palette() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    let palette = Palette::load("./assets/palette.txt").unwrap();
+    // Transparent pixel between sand and water
+    let sprite = Sprite {
+        pixels: vec![0xFFFFFFFF, 0x00FFFFFF, 0xFF0070BA],
+        width: 3,
+        height: 1,
+    };
+
+    // Tested
+    sim.insert_sprite(sprite, &Offset::new(2, 2), |color| palette.translate(color));
+
+    // Check
+    let name_at = |x, y| {
+        sim.get_particle(&Offset::new(x, y))
+            .as_ref()
+            .map(|p| p.get_name().to_string())
+    };
+    assert_eq!(name_at(2, 2).as_deref(), Some("Sand"));
+    assert_eq!(name_at(3, 2), None);
+    assert_eq!(name_at(4, 2).as_deref(), Some("Water"));
+}
+
+#[test]
+fn palette_to_text_and_back() -> () {
+    // Setup
+    let palette = Palette::default();
+
+    // Tested
+    let parsed = Palette::parse(&palette.to_string()).unwrap();
+
+    // Check
+    assert_eq!(parsed.to_string(), palette.to_string());
+    assert_eq!(parsed.color_of("Water"), palette.color_of("Water"));
+}
@@ -0,0 +1,60 @@
+use particle_simulation::{
+    particles::{Mud, Rock, Seed},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn seed_grows_into_plant() -> () {
+    // Setup - a seed dropped on a layer of mud
+    let mut sim = Simulation::new(20, 40);
+    sim.set_seed(1);
+    for x in 0..20 {
+        sim.add_particle(&Offset::new(x, 39), Mud::new());
+    }
+    sim.add_particle(&Offset::new(10, 30), Seed::new());
+
+    // Tested
+    for _ in 0..600 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Seed"), 0);
+    assert!(count(&sim, "Plant") > 10);
+}
+
+#[test]
+fn plant_stops_at_obstacles() -> () {
+    // Setup - a seed under a low rock ceiling
+    let mut sim = Simulation::new(20, 20);
+    sim.set_seed(2);
+    for x in 0..20 {
+        sim.add_particle(&Offset::new(x, 19), Mud::new());
+        sim.add_particle(&Offset::new(x, 14), Rock::new());
+    }
+    sim.add_particle(&Offset::new(10, 18), Seed::new());
+
+    // Tested
+    for _ in 0..600 {
+        sim.simulate_step();
+    }
+
+    // Check - the plant fills at most the space under the ceiling
+    let above_ceiling = sim
+        .cells_iter()
+        .enumerate()
+        .filter(|(i, cell)| {
+            i / 20 < 14
+                && match cell.get_particle() {
+                    Some(p) => p.get_name() == "Plant",
+                    None => false,
+                }
+        })
+        .count();
+    assert!(count(&sim, "Plant") > 0);
+    assert_eq!(above_ceiling, 0);
+    assert_eq!(count(&sim, "Rock"), 20);
+}
@@ -0,0 +1,155 @@
+use particle_simulation::{
+    particles::{MatterType, Rock, Sand, Water},
+    Offset, Rect, Simulation,
+};
+
+#[test]
+fn particles_in_shapes() -> () {
+    // Setup
+    let mut sim = Simulation::new(20, 20);
+    for off in Rect::new(Offset::zero(), 20, 20).offsets() {
+        sim.add_particle(&off, Rock::new());
+    }
+
+    // Tested
+    let in_rect = sim
+        .particles_in_rect(&Rect::new(Offset::new(18, 18), 5, 5))
+        .count();
+    let in_circle: Vec<Offset> = sim
+        .particles_in_circle(&Offset::new(5, 5), 1)
+        .map(|(off, _)| off)
+        .collect();
+
+    // Check
+    // Only the part inside the simulation
+    assert_eq!(in_rect, 4);
+    assert_eq!(
+        in_circle,
+        vec![
+            Offset::new(5, 4),
+            Offset::new(4, 5),
+            Offset::new(5, 5),
+            Offset::new(6, 5),
+            Offset::new(5, 6)
+        ]
+    );
+}
+
+#[test]
+fn particles_in_huge_circle() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(9, 9), Water::new());
+
+    // Tested
+    let around: Vec<Offset> = sim
+        .particles_in_circle(&Offset::new(5, 5), u32::MAX)
+        .map(|(off, _)| off)
+        .collect();
+    let far_away = sim
+        .particles_in_circle(&Offset::new(i32::MIN, i32::MAX), u32::MAX)
+        .count();
+    let outside = sim.particles_in_circle(&Offset::new(-100, 5), 50).count();
+
+    // Check
+    assert_eq!(around, vec![Offset::new(0, 0), Offset::new(9, 9)]);
+    assert_eq!(far_away, 2);
+    assert_eq!(outside, 0);
+}
+
+#[test]
+fn count_particles() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    sim.add_particle(&Offset::new(1, 1), Sand::new());
+    sim.add_particle(&Offset::new(2, 1), Sand::new());
+    sim.add_particle(&Offset::new(3, 1), Water::new());
+    // Outside of the counted area
+    sim.add_particle(&Offset::new(8, 8), Water::new());
+    let area = Rect::new(Offset::zero(), 5, 5);
+
+    // Tested
+    let counts = sim.count_by_name(&area);
+    let liquids = sim.count_by_matter_type(&area, MatterType::Liquid);
+
+    // Check
+    assert_eq!(counts.get("Sand"), Some(&2));
+    assert_eq!(counts.get("Water"), Some(&1));
+    assert_eq!(counts.get("Rock"), None);
+    assert_eq!(liquids, 1);
+}
+
+#[test]
+fn count_particles_in_huge_rect() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(9, 9), Water::new());
+    let huge = Rect::new(Offset::new(-100, -100), u32::MAX, u32::MAX);
+    let far_away = Rect::new(Offset::new(i32::MAX, 0), u32::MAX, u32::MAX);
+
+    // Tested
+    let counts = sim.count_by_name(&huge);
+    let outside = sim.count_by_name(&far_away);
+
+    // Check - only the part inside the simulation is counted
+    assert_eq!(counts.get("Sand"), Some(&1));
+    assert_eq!(counts.get("Water"), Some(&1));
+    assert!(outside.is_empty());
+}
+
+#[test]
+fn find_nearest() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(6, 5), Sand::new());
+    sim.add_particle(&Offset::new(5, 5), Water::new());
+
+    // Tested & Check
+    assert_eq!(
+        sim.find_nearest(&Offset::new(4, 4), "Sand"),
+        Some(Offset::new(6, 5))
+    );
+    assert_eq!(sim.find_nearest(&Offset::new(4, 4), "Rock"), None);
+}
+
+#[test]
+fn find_nearest_from_far_away() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(9, 9), Sand::new());
+
+    // Tested & Check
+    assert_eq!(
+        sim.find_nearest(&Offset::new(i32::MIN, i32::MIN), "Sand"),
+        Some(Offset::new(0, 0))
+    );
+    assert_eq!(
+        sim.find_nearest(&Offset::new(i32::MAX, i32::MAX), "Sand"),
+        Some(Offset::new(9, 9))
+    );
+}
+
+#[test]
+fn find_blob() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    // L shaped blob of rock, with a diagonal rock and sand next to it that are not part of it
+    for off in [(2, 2), (2, 3), (2, 4), (3, 4), (4, 4)] {
+        sim.add_particle(&Offset::new(off.0, off.1), Rock::new());
+    }
+    sim.add_particle(&Offset::new(5, 5), Rock::new());
+    sim.add_particle(&Offset::new(3, 3), Sand::new());
+
+    // Tested
+    let blob = sim.find_blob(&Offset::new(2, 2)).unwrap();
+
+    // Check
+    assert_eq!(blob.size(), 5);
+    assert_eq!(blob.bounding_box(), Rect::new(Offset::new(2, 2), 3, 3));
+    assert_eq!(blob.centroid(), (2.6, 3.4));
+    assert!(sim.find_blob(&Offset::new(0, 0)).is_none());
+}
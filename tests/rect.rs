@@ -0,0 +1,19 @@
+use particle_simulation::{Offset, Rect};
+
+#[test]
+fn from_corners() -> () {
+    let rect = Rect::from_corners(&Offset::new(5, 2), &Offset::new(1, 4));
+
+    assert_eq!(rect, Rect::new(Offset::new(1, 2), 5, 3));
+}
+
+#[test]
+fn contains() -> () {
+    let rect = Rect::new(Offset::new(1, 1), 2, 2);
+
+    assert!(rect.contains(&Offset::new(1, 1)));
+    assert!(rect.contains(&Offset::new(2, 2)));
+    assert!(!rect.contains(&Offset::new(3, 2)));
+    assert!(!rect.contains(&Offset::new(0, 1)));
+    assert_eq!(rect.offsets().len(), 4);
+}
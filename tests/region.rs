@@ -0,0 +1,90 @@
+use particle_simulation::{
+    particles::{Rock, Sand, Water},
+    Offset, PasteMode, Rect, Simulation, Velocity,
+};
+
+fn name_at(sim: &Simulation, x: i32, y: i32) -> Option<String> {
+    sim.get_particle(&Offset::new(x, y))
+        .as_ref()
+        .map(|p| p.get_name().to_string())
+}
+
+#[test]
+fn extract_keeps_state() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    let mut sand = Sand::new();
+    sand.set_velocity(Velocity::new(1.5, -2.0));
+    sim.add_particle(&Offset::new(2, 3), sand);
+    sim.add_particle(&Offset::new(3, 3), Water::new());
+
+    // Tested
+    let region = sim.extract_region(&Rect::new(Offset::new(2, 3), 3, 2));
+    let mut other = Simulation::new(5, 5);
+    other.paste_region(&region, &Offset::new(1, 1), PasteMode::Overwrite);
+
+    // Check
+    assert_eq!(region.particle_count(), 2);
+    assert_eq!((region.width(), region.height()), (3, 2));
+    let velocity = other
+        .get_particle(&Offset::new(1, 1))
+        .as_ref()
+        .unwrap()
+        .get_velocity();
+    assert_eq!((velocity.x, velocity.y), (1.5, -2.0));
+    assert_eq!(name_at(&other, 2, 1).as_deref(), Some("Water"));
+}
+
+#[test]
+fn paste_modes() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    // Region of sand with an empty cell on the right
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(1, 0), Sand::new());
+    let region = sim.extract_region(&Rect::new(Offset::zero(), 3, 1));
+    for mode in [PasteMode::Overwrite, PasteMode::Merge, PasteMode::FillEmpty] {
+        for x in 4..7 {
+            sim.remove_particle(&Offset::new(x, 5));
+        }
+        sim.add_particle(&Offset::new(4, 5), Rock::new());
+        sim.add_particle(&Offset::new(6, 5), Rock::new());
+
+        // Tested
+        sim.paste_region(&region, &Offset::new(4, 5), mode);
+
+        // Check
+        let row: Vec<Option<String>> = (4..7).map(|x| name_at(&sim, x, 5)).collect();
+        let expected = match mode {
+            PasteMode::Overwrite => [Some("Sand"), Some("Sand"), None],
+            PasteMode::Merge => [Some("Sand"), Some("Sand"), Some("Rock")],
+            PasteMode::FillEmpty => [Some("Rock"), Some("Sand"), Some("Rock")],
+        };
+        assert_eq!(row, expected.map(|name| name.map(String::from)).to_vec());
+    }
+}
+
+#[test]
+fn transform_region() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    sim.add_particle(&Offset::new(0, 0), Sand::new());
+    sim.add_particle(&Offset::new(1, 0), Water::new());
+    let region = sim.extract_region(&Rect::new(Offset::zero(), 2, 1));
+
+    // Tested
+    let rotated = region.rotated(1);
+    let flipped = region.flipped_horizontally();
+
+    // Check
+    assert_eq!((rotated.width(), rotated.height()), (1, 2));
+    assert_eq!(
+        rotated.get_particle(0, 1).as_ref().unwrap().get_name(),
+        "Water"
+    );
+    assert_eq!(
+        flipped.get_particle(0, 0).as_ref().unwrap().get_name(),
+        "Water"
+    );
+    assert!(region.flipped_vertically().get_particle(0, 0).is_some());
+}
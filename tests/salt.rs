@@ -0,0 +1,116 @@
+use particle_simulation::{
+    particles::{Burnability, Rock, Salt, SaltWater, Tnt, Water},
+    Offset, Simulation,
+};
+
+mod common;
+use common::count;
+
+#[test]
+fn salt_dissolves_in_water() -> () {
+    // Setup - salt poured into a pool of water
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(1);
+    for x in 0..10 {
+        for y in 6..10 {
+            sim.add_particle(&Offset::new(x, y), Water::new());
+        }
+    }
+    for x in 3..7 {
+        sim.add_particle(&Offset::new(x, 2), Salt::new());
+    }
+
+    // Tested
+    for _ in 0..50 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(count(&sim, "Salt"), 0);
+    assert_eq!(count(&sim, "Salt Water"), 4);
+    assert_eq!(count(&sim, "Water"), 36);
+}
+
+#[test]
+fn salt_water_sinks_below_water() -> () {
+    // Setup - layer of salt water on top of fresh water
+    let mut sim = Simulation::new(10, 10);
+    sim.set_seed(2);
+    for x in 0..10 {
+        for y in 7..10 {
+            sim.add_particle(&Offset::new(x, y), Water::new());
+        }
+        for y in 4..7 {
+            sim.add_particle(&Offset::new(x, y), SaltWater::new());
+        }
+    }
+
+    // Tested
+    for _ in 0..300 {
+        sim.simulate_step();
+    }
+
+    // Check - the bottom row is all salt water
+    let bottom_salt_water = (0..10)
+        .filter(|x| match sim.get_particle(&Offset::new(*x, 9)) {
+            Some(p) => p.get_name() == "Salt Water",
+            None => false,
+        })
+        .count();
+    assert_eq!(bottom_salt_water, 10);
+}
+
+#[test]
+fn two_salts_dissolve_in_one_water() -> () {
+    // Setup - a single water between two grains of salt, walled in by rock
+    let mut sim = Simulation::new(5, 3);
+    sim.set_seed(2);
+    for x in 0..5 {
+        sim.add_particle(&Offset::new(x, 2), Rock::new());
+    }
+    sim.add_particle(&Offset::new(0, 1), Rock::new());
+    sim.add_particle(&Offset::new(1, 1), Salt::new());
+    sim.add_particle(&Offset::new(2, 1), Water::new());
+    sim.add_particle(&Offset::new(3, 1), Salt::new());
+    sim.add_particle(&Offset::new(4, 1), Rock::new());
+
+    // Tested
+    sim.simulate_step();
+
+    // Check - only one salt fits into the water, the other one is left over
+    assert_eq!(count(&sim, "Salt Water"), 1);
+    assert_eq!(count(&sim, "Salt"), 1);
+    assert_eq!(count(&sim, "Water"), 0);
+}
+
+#[test]
+fn salt_destroyed_by_explosion_stays_destroyed() -> () {
+    // Setup - salt next to water under a charge that explodes in the same step the salt dissolves
+    let mut sim = Simulation::new(5, 3);
+    sim.set_seed(3);
+    for x in 0..5 {
+        sim.add_particle(&Offset::new(x, 2), Rock::new());
+    }
+    sim.add_particle(&Offset::new(0, 1), Rock::new());
+    sim.add_particle(&Offset::new(1, 1), Salt::new());
+    sim.add_particle(&Offset::new(2, 1), Water::new());
+    sim.add_particle(&Offset::new(3, 1), Rock::new());
+    let mut tnt = Tnt::new();
+    tnt.set_burnability(Burnability::IsBurning(0));
+    sim.add_particle(&Offset::new(1, 0), tnt);
+
+    // Tested
+    sim.simulate_step();
+
+    // Check - the explosion took the water, but the salt does not come back
+    assert_eq!(count(&sim, "TNT"), 0);
+    assert_eq!(count(&sim, "Water"), 0);
+    assert_eq!(count(&sim, "Salt"), 0);
+}
+
+#[test]
+fn particle_type_check() -> () {
+    assert!(Water::new().is::<Water>());
+    assert!(!SaltWater::new().is::<Water>());
+    assert!(SaltWater::new().is::<SaltWater>());
+}
@@ -0,0 +1,342 @@
+#![cfg(feature = "scripting")]
+
+use particle_simulation::{particles::MatterType, scripting::Scripting, Simulation};
+
+#[test]
+fn scene_script() -> () {
+    // Setup
+    let scripting = Scripting::new();
+    let script = r#"
+        sim.reset(20, 20);
+        sim.fill(0, 19, 20, 1, "Rock");
+        sim.add(10, 0, "Sand");
+        sim.step(100);
+    "#;
+
+    // Tested
+    let mut sim = Simulation::new(1, 1);
+    scripting.run_scene(script, &mut sim).unwrap();
+
+    // Check
+    assert_eq!((sim.width(), sim.height()), (20, 20));
+    let sand_bottom = sim
+        .get_particle(&particle_simulation::Offset::new(10, 18))
+        .as_ref()
+        .map(|p| p.get_name().to_string());
+    assert_eq!(sand_bottom.as_deref(), Some("Sand"));
+}
+
+#[test]
+fn scene_script_errors() -> () {
+    let scripting = Scripting::new();
+
+    assert!(scripting
+        .run_scene(
+            r#"sim.add(0, 0, "Unobtainium");"#,
+            &mut Simulation::new(5, 5)
+        )
+        .is_err());
+    assert!(scripting
+        .run_scene("this is not rhai", &mut Simulation::new(5, 5))
+        .is_err());
+}
+
+#[test]
+fn scripted_particle() -> () {
+    // Setup
+    let mut scripting = Scripting::new();
+    // Falls straight down and turns into rock after 30 updates
+    let script = r#"
+        fn init() { #{ age: 0 } }
+
+        fn update(n) {
+            this.age += 1;
+            if this.age >= 30 {
+                return #{ become: "Rock" };
+            }
+            let down = n.down();
+            if n.is_empty(down[0], down[1]) {
+                #{ move: down }
+            }
+        }
+    "#;
+    scripting
+        .define_particle("Pebble", 0x777777, 180, MatterType::Solid, script)
+        .unwrap();
+    let scene = r#"
+        sim.add(5, 0, "Pebble");
+        sim.step(10);
+        if sim.name_at(5, 9) != "Pebble" { throw "Pebble did not fall"; }
+        sim.step(30);
+        if sim.count("Rock") != 1 { throw "Pebble did not turn into rock"; }
+    "#;
+
+    // Tested & Check
+    let result = scripting.run_scene(scene, &mut Simulation::new(10, 10));
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[test]
+fn scene_script_errors_keep_simulation() -> () {
+    // Setup
+    let scripting = Scripting::new();
+    let mut sim = Simulation::new(5, 5);
+
+    // Tested
+    let result = scripting.run_scene(
+        r#"sim.add(1, 1, "Sand"); sim.add(2, 2, "Unobtainium");"#,
+        &mut sim,
+    );
+
+    // Check - the simulation is kept with the changes made before the error
+    assert!(result.is_err());
+    assert_eq!((sim.width(), sim.height()), (5, 5));
+    let sand = sim
+        .get_particle(&particle_simulation::Offset::new(1, 1))
+        .as_ref()
+        .map(|p| p.get_name().to_string());
+    assert_eq!(sand.as_deref(), Some("Sand"));
+}
+
+#[test]
+fn invalid_particle_script() -> () {
+    let mut scripting = Scripting::new();
+
+    assert!(scripting
+        .define_particle("Nothing", 0, 0, MatterType::Gas, "fn init() { 0 }")
+        .is_err());
+    assert!(scripting
+        .define_particle("Sand", 0, 0, MatterType::Solid, "fn update(n) {}")
+        .is_err());
+}
+
+#[test]
+fn endless_scripts_are_stopped() -> () {
+    // Setup
+    let mut scripting = Scripting::new();
+    scripting
+        .define_particle(
+            "Spinner",
+            0xFFFFFF,
+            100,
+            MatterType::Solid,
+            "fn update(n) { loop {} }",
+        )
+        .unwrap();
+    let scene = r#"
+        sim.add(2, 2, "Spinner");
+        sim.step(2);
+    "#;
+
+    // Tested & Check - both scripts run out of operations instead of hanging
+    let result = scripting.run_scene(scene, &mut Simulation::new(5, 5));
+    assert!(result.is_ok(), "{:?}", result.err());
+    assert!(scripting
+        .run_scene("loop {}", &mut Simulation::new(5, 5))
+        .is_err());
+}
+
+#[test]
+fn particle_script_errors_are_kept() -> () {
+    // Setup
+    let mut scripting = Scripting::new();
+    let broken = scripting
+        .define_particle(
+            "Broken",
+            0xFFFFFF,
+            100,
+            MatterType::Solid,
+            "fn update(n) { n.no_such_method() }",
+        )
+        .unwrap();
+    assert!(broken.error().is_none());
+
+    // Tested
+    let scene = r#"
+        sim.add(2, 2, "Broken");
+        sim.step(3);
+        if sim.count("Broken") != 1 { throw "Broken particle disappeared"; }
+    "#;
+    let result = scripting.run_scene(scene, &mut Simulation::new(5, 5));
+
+    // Check
+    assert!(result.is_ok(), "{:?}", result.err());
+    assert!(broken.error().is_some_and(|e| e.contains("no_such_method")));
+}
+
+#[test]
+fn scripted_particles_create_each_other() -> () {
+    // Setup - egg hatches into a chick defined after it, a nest lays eggs above it
+    let mut scripting = Scripting::new();
+    scripting
+        .define_particle(
+            "Egg",
+            0xFFFFEE,
+            200,
+            MatterType::Solid,
+            r#"fn update(n) { #{ become: "Chick" } }"#,
+        )
+        .unwrap();
+    scripting
+        .define_particle("Chick", 0xFFEE00, 200, MatterType::Solid, "fn update(n) {}")
+        .unwrap();
+    scripting
+        .define_particle(
+            "Nest",
+            0x8B5A2B,
+            200,
+            MatterType::Solid,
+            r#"fn update(n) { #{ "spawn": "Egg", spawn_at: [0, -1] } }"#,
+        )
+        .unwrap();
+    let scene = r#"
+        sim.set_gravity(0.0, 0.0);
+        sim.add(2, 4, "Nest");
+        sim.step(2);
+        if sim.count("Chick") != 1 { throw "Egg did not hatch"; }
+    "#;
+
+    // Tested & Check
+    let result = scripting.run_scene(scene, &mut Simulation::new(5, 5));
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[test]
+fn unknown_particle_in_script() -> () {
+    // Setup
+    let mut scripting = Scripting::new();
+    let dreamer = scripting
+        .define_particle(
+            "Dreamer",
+            0xFFFFFF,
+            100,
+            MatterType::Solid,
+            r#"fn update(n) { #{ become: "Unicorn" } }"#,
+        )
+        .unwrap();
+    let scene = r#"
+        sim.add(2, 2, "Dreamer");
+        sim.step(2);
+        if sim.count("Dreamer") != 1 { throw "Dreamer disappeared"; }
+    "#;
+
+    // Tested
+    let result = scripting.run_scene(scene, &mut Simulation::new(5, 5));
+
+    // Check - the particle stays and the unknown name is reported
+    assert!(result.is_ok(), "{:?}", result.err());
+    assert!(dreamer.error().is_some_and(|e| e.contains("Unicorn")));
+}
+
+#[test]
+fn random_in_empty_range() -> () {
+    let scripting = Scripting::new();
+
+    assert!(scripting
+        .run_scene(
+            "let x = rand_int(1, 5); if x < 1 || x > 5 { throw x; }",
+            &mut Simulation::new(1, 1)
+        )
+        .is_ok());
+    assert!(scripting
+        .run_scene("rand_int(5, 1);", &mut Simulation::new(1, 1))
+        .is_err());
+}
+
+#[test]
+fn far_script_offsets_are_limited() -> () {
+    // Setup - particles trying to move and spawn far out of the simulation
+    let mut scripting = Scripting::new();
+    scripting
+        .define_particle(
+            "Rocket",
+            0xFFFFFF,
+            100,
+            MatterType::Solid,
+            "fn update(n) { #{ move: [2147483647, 0] } }",
+        )
+        .unwrap();
+    scripting
+        .define_particle(
+            "Launcher",
+            0xFFFFFF,
+            100,
+            MatterType::Solid,
+            r#"fn update(n) { #{ "spawn": "Sand", spawn_at: [-2147483648, 2147483647] } }"#,
+        )
+        .unwrap();
+    let scene = r#"
+        sim.set_gravity(0.0, 0.0);
+        sim.add(0, 2, "Rocket");
+        sim.add(2, 0, "Launcher");
+        sim.step(2);
+        if sim.name_at(5, 2) != "Rocket" { throw "Rocket did not move by the maximal velocity"; }
+        if sim.name_at(1, 1) != "Sand" { throw "Sand was not spawned next to the launcher"; }
+    "#;
+
+    // Tested & Check
+    let result = scripting.run_scene(scene, &mut Simulation::new(10, 10));
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[test]
+fn out_of_range_coordinates() -> () {
+    // Setup
+    let mut scripting = Scripting::new();
+    let stray = scripting
+        .define_particle(
+            "Stray",
+            0xFFFFFF,
+            100,
+            MatterType::Solid,
+            "fn update(n) { #{ move: [4294967297, 0] } }",
+        )
+        .unwrap();
+
+    // Tested & Check - coordinates that do not fit are errors instead of wrapping around
+    assert!(scripting
+        .run_scene(
+            r#"sim.add(4294967297, 1, "Sand");"#,
+            &mut Simulation::new(5, 5)
+        )
+        .is_err());
+    assert!(scripting
+        .run_scene("sim.remove(1, -4294967295);", &mut Simulation::new(5, 5))
+        .is_err());
+    assert!(scripting
+        .run_scene("sim.name_at(4294967297, 1);", &mut Simulation::new(5, 5))
+        .is_err());
+    let scene = r#"
+        sim.add(2, 2, "Stray");
+        sim.step(2);
+        if sim.name_at(2, 2) != "Stray" { throw "Stray moved"; }
+    "#;
+    let result = scripting.run_scene(scene, &mut Simulation::new(5, 5));
+    assert!(result.is_ok(), "{:?}", result.err());
+    assert!(stray.error().is_some_and(|e| e.contains("out of range")));
+}
+
+#[test]
+fn scene_sizes_are_limited() -> () {
+    let scripting = Scripting::new();
+
+    // Fill is cut to the simulation instead of going through the whole area
+    let mut sim = Simulation::new(5, 5);
+    scripting
+        .run_scene(
+            r#"sim.fill(-1000000000, 3, 2000000000, 1000000000, "Rock");"#,
+            &mut sim,
+        )
+        .unwrap();
+    let rocks = sim.cells_iter().filter(|cell| !cell.is_empty()).count();
+    assert_eq!(rocks, 10);
+    assert!(scripting
+        .run_scene(
+            "sim.reset(1000000000, 1000000000);",
+            &mut Simulation::new(5, 5)
+        )
+        .is_err());
+    assert!(scripting
+        .run_scene("sim.reset(-1, 5);", &mut Simulation::new(5, 5))
+        .is_err());
+}
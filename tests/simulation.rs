@@ -0,0 +1,177 @@
+use fastrand::Rng;
+use particle_simulation::{
+    particles::{Lava, Oil, Particle, Rock, Sand, Water, Wood},
+    ForceField, Gravity, Offset, Rect, Simulation, Velocity,
+};
+
+mod common;
+use common::{count, count_all, whole};
+
+const WIDTH: usize = 50;
+const HEIGHT: usize = 50;
+const STEPS: usize = 300;
+
+/// Adds `amount` particles on random offsets inside the area, the same for the same seed
+fn scatter(
+    sim: &mut Simulation,
+    rng: &mut Rng,
+    area: &Rect,
+    amount: usize,
+    new_fn: fn() -> Box<dyn Particle>,
+) -> () {
+    let mut added = 0;
+    while added < amount {
+        let off = area.offset
+            + Offset::new(
+                rng.i32(0..area.width as i32),
+                rng.i32(0..area.height as i32),
+            );
+        if sim.add_particle(&off, new_fn()) {
+            added += 1;
+        }
+    }
+}
+
+/// Surrounds the simulation with a wall of rock
+fn add_walls(sim: &mut Simulation) -> () {
+    let (w, h) = (sim.width() as i32, sim.height() as i32);
+    for off in whole(sim).offsets() {
+        if off.x == 0 || off.y == 0 || off.x == w - 1 || off.y == h - 1 {
+            sim.add_particle(&off, Rock::new());
+        }
+    }
+}
+
+/// Names of particles in all cells
+fn snapshot(sim: &Simulation) -> Vec<Option<String>> {
+    sim.cells_iter()
+        .map(|cell| {
+            cell.get_particle()
+                .as_ref()
+                .map(|p| p.get_name().to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn sand_is_conserved() -> () {
+    for seed in 0..4 {
+        // Setup
+        let mut sim = Simulation::new(WIDTH, HEIGHT);
+        sim.set_seed(seed);
+        let mut rng = Rng::with_seed(seed);
+        let all = whole(&sim);
+        scatter(&mut sim, &mut rng, &all, 600, Sand::new);
+
+        // Tested & Check
+        for _ in 0..STEPS {
+            sim.simulate_step();
+            assert_eq!(count(&sim, "Sand"), 600, "seed {}", seed);
+        }
+    }
+}
+
+#[test]
+fn liquids_are_conserved_without_heat() -> () {
+    for seed in 0..4 {
+        // Setup
+        let mut sim = Simulation::new(WIDTH, HEIGHT);
+        sim.set_seed(seed);
+        let mut rng = Rng::with_seed(seed);
+        add_walls(&mut sim);
+        let inside = Rect::new(Offset::new(1, 1), WIDTH as u32 - 2, HEIGHT as u32 - 2);
+        scatter(&mut sim, &mut rng, &inside, 500, Water::new);
+        scatter(&mut sim, &mut rng, &inside, 300, Oil::new);
+        scatter(&mut sim, &mut rng, &inside, 200, Sand::new);
+
+        // Tested & Check
+        for _ in 0..STEPS {
+            sim.simulate_step();
+            assert_eq!(count(&sim, "Water"), 500, "seed {}", seed);
+            assert_eq!(count(&sim, "Oil"), 300, "seed {}", seed);
+        }
+    }
+}
+
+#[test]
+fn particles_stay_in_bounds() -> () {
+    // Setup - a rock box in the middle of the simulation, filled with sand and water
+    let mut sim = Simulation::new(WIDTH, HEIGHT);
+    sim.set_seed(42);
+    let mut rng = Rng::with_seed(42);
+    let walls = Rect::new(Offset::new(10, 10), 30, 30);
+    let inside = Rect::new(Offset::new(11, 11), 28, 28);
+    for off in walls.offsets() {
+        if !inside.contains(&off) {
+            sim.add_particle(&off, Rock::new());
+        }
+    }
+    let wall_count = count(&sim, "Rock");
+    scatter(&mut sim, &mut rng, &inside, 300, Sand::new);
+    scatter(&mut sim, &mut rng, &inside, 300, Water::new);
+    // Wind pushing everything to the right wall
+    sim.add_force_field(ForceField::wind(whole(&sim), Velocity::new(1.0, 0.0), 2.0));
+
+    // Tested & Check
+    // Strong gravity towards each wall in turn, nothing may leave the box or disappear
+    for angle in [0.0, 90.0, 180.0, -90.0] {
+        sim.set_gravity(Gravity::from_angle(angle, 0.5));
+        for _ in 0..STEPS / 4 {
+            sim.simulate_step();
+
+            let counts = sim.count_by_name(&inside);
+            assert_eq!(counts.get("Sand").copied(), Some(300), "angle {}", angle);
+            assert_eq!(counts.get("Water").copied(), Some(300), "angle {}", angle);
+            assert_eq!(count(&sim, "Rock"), wall_count, "angle {}", angle);
+            assert_eq!(count_all(&sim), wall_count + 600, "angle {}", angle);
+        }
+    }
+}
+
+#[test]
+fn particle_count_matches() -> () {
+    // Setup
+    let mut sim = Simulation::new(WIDTH, HEIGHT);
+    sim.set_seed(3);
+    let mut rng = Rng::with_seed(7);
+    let all = whole(&sim);
+    scatter(&mut sim, &mut rng, &all, 300, Water::new);
+    scatter(&mut sim, &mut rng, &all, 200, Wood::new);
+
+    // Tested & Check
+    sim.remove_particle(&Offset::new(0, 0));
+    sim.change_particle(&Offset::new(1, 0), Lava::new());
+    sim.change_particle(&Offset::new(2, 0), Lava::new());
+    assert_eq!(sim.info().particle_count as usize, count_all(&sim));
+
+    // Lava boils water, burns wood and cools down, creating and destroying particles
+    scatter(&mut sim, &mut rng, &all, 200, Lava::new);
+    for _ in 0..STEPS {
+        sim.simulate_step();
+        assert_eq!(sim.info().particle_count as usize, count_all(&sim));
+    }
+}
+
+#[test]
+fn closed_box_reaches_steady_state() -> () {
+    // Setup
+    let mut sim = Simulation::new(WIDTH, HEIGHT);
+    sim.set_seed(4);
+    let mut rng = Rng::with_seed(3);
+    add_walls(&mut sim);
+    let inside = Rect::new(Offset::new(1, 1), WIDTH as u32 - 2, HEIGHT as u32 - 2);
+    scatter(&mut sim, &mut rng, &inside, 700, Sand::new);
+
+    // Tested
+    for _ in 0..STEPS * 2 {
+        sim.simulate_step();
+    }
+    let settled = snapshot(&sim);
+    for _ in 0..20 {
+        sim.simulate_step();
+    }
+
+    // Check
+    assert_eq!(sim.info().moves_made_last_frame, 0);
+    assert!(snapshot(&sim) == settled);
+}
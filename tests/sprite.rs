@@ -1,4 +1,13 @@
-use particle_simulation::Sprite;
+use particle_simulation::{particles::Sand, Offset, Rect, Simulation, Sprite};
+
+/// 3x2 sprite with pixels numbered row by row
+fn numbered_sprite() -> Sprite {
+    Sprite {
+        pixels: vec![1, 2, 3, 4, 5, 6],
+        width: 3,
+        height: 2,
+    }
+}
 
 #[test]
 fn load_sprite() -> () {
@@ -28,3 +37,100 @@ fn unique_colors() -> () {
     assert!(colors.contains(&(0xFF0070BA as u32)));
     assert!(colors.contains(&(0xFFFFFFFF as u32)));
 }
+
+#[test]
+fn scale_sprite() -> () {
+    // Setup
+    let sprite = numbered_sprite();
+
+    // Tested
+    let scaled = sprite.scaled_by(2.0);
+    let loaded = Sprite::load("./assets/fit_pixel.png").unwrap().scaled(7, 5);
+
+    // Check
+    assert_eq!((scaled.width, scaled.height), (6, 4));
+    assert_eq!(scaled.pixels[0..6], [1, 1, 2, 2, 3, 3]);
+    assert_eq!(scaled.pixels[18..24], [4, 4, 5, 5, 6, 6]);
+    assert_eq!((loaded.width, loaded.height), (7, 5));
+}
+
+#[test]
+fn scale_empty_sprite() -> () {
+    // Setup - crop outside of the sprite leaves no pixels
+    let empty = numbered_sprite().cropped(&Rect::new(Offset::new(5, 0), 2, 2));
+
+    // Tested
+    let scaled = empty.scaled(4, 3);
+    let scaled_by = empty.scaled_by(2.0);
+
+    // Check
+    assert_eq!((empty.width, empty.height), (0, 2));
+    assert_eq!((scaled.width, scaled.height), (4, 3));
+    assert!(scaled
+        .pixels
+        .iter()
+        .all(|&color| Sprite::is_transparent(color)));
+    assert_eq!((scaled_by.width, scaled_by.height), (1, 4));
+    assert!(Sprite::is_transparent(scaled_by.pixels[0]));
+}
+
+#[test]
+fn rotate_sprite() -> () {
+    // Setup
+    let sprite = numbered_sprite();
+
+    // Tested
+    let clockwise = sprite.rotated(1);
+    let upside_down = sprite.rotated(2);
+    let counterclockwise = sprite.rotated(-1);
+
+    // Check
+    assert_eq!((clockwise.width, clockwise.height), (2, 3));
+    assert_eq!(clockwise.pixels, vec![4, 1, 5, 2, 6, 3]);
+    assert_eq!(upside_down.pixels, vec![6, 5, 4, 3, 2, 1]);
+    assert_eq!(counterclockwise.pixels, vec![3, 6, 2, 5, 1, 4]);
+    assert_eq!(sprite.rotated(4).pixels, sprite.pixels);
+}
+
+#[test]
+fn flip_and_crop_sprite() -> () {
+    // Setup
+    let sprite = numbered_sprite();
+
+    // Tested
+    let horizontal = sprite.flipped_horizontally();
+    let vertical = sprite.flipped_vertically();
+    let cropped = sprite.cropped(&Rect::new(Offset::new(1, 1), 5, 5));
+
+    // Check
+    assert_eq!(horizontal.pixels, vec![3, 2, 1, 6, 5, 4]);
+    assert_eq!(vertical.pixels, vec![4, 5, 6, 1, 2, 3]);
+    assert_eq!((cropped.width, cropped.height), (2, 1));
+    assert_eq!(cropped.pixels, vec![5, 6]);
+}
+
+#[test]
+fn insert_tiled_sprite() -> () {
+    // Setup
+    let mut sim = Simulation::new(10, 10);
+    // Checkerboard of opaque and transparent pixels
+    let sprite = Sprite {
+        pixels: vec![0xFFFFFFFF, 0, 0, 0xFFFFFFFF],
+        width: 2,
+        height: 2,
+    };
+
+    // Tested
+    sim.insert_sprite_tiled(&sprite, &Rect::new(Offset::new(1, 1), 5, 3), |_| {
+        Some(Sand::new())
+    });
+
+    // Check
+    for y in 0..10 {
+        for x in 0..10 {
+            let inside = (1..6).contains(&x) && (1..4).contains(&y);
+            let expected = inside && (x + y) % 2 == 0;
+            assert_eq!(sim.get_particle(&Offset::new(x, y)).is_some(), expected);
+        }
+    }
+}
@@ -10,6 +10,21 @@ fn value_around() -> () {
     assert!(res >= (middle - radius) && res <= (middle + radius));
 }
 
+#[test]
+fn value_around_is_seeded() -> () {
+    // Setup
+    fastrand::seed(7);
+    let first: Vec<u8> = (0..10).map(|_| get_value_around(250, 5)).collect();
+
+    // Tested
+    fastrand::seed(7);
+    let second: Vec<u8> = (0..10).map(|_| get_value_around(250, 5)).collect();
+
+    // Check
+    assert_eq!(first, second);
+    assert!(first.iter().all(|v| *v >= 245));
+}
+
 #[test]
 fn for_else_early() -> () {
     let mut early_flag = false;
@@ -0,0 +1,39 @@
+use particle_simulation::{Offset, Velocity};
+
+#[test]
+fn movement_accumulates_fractions() -> () {
+    // Setup
+    let velocity = Velocity::new(0.5, 1.25);
+    let mut carry = Velocity::zero();
+
+    // Tested method
+    let first = velocity.movement(&mut carry);
+    let second = velocity.movement(&mut carry);
+
+    // Check
+    assert_eq!(first, Offset::new(0, 1));
+    assert_eq!(second, Offset::new(1, 1));
+    assert_eq!(carry, Velocity::new(0.0, 0.5));
+}
+
+#[test]
+fn movement_negative() -> () {
+    // Setup
+    let velocity = Velocity::new(-1.5, -0.5);
+    let mut carry = Velocity::zero();
+
+    // Tested method
+    let movement = velocity.movement(&mut carry);
+
+    // Check
+    assert_eq!(movement, Offset::new(-1, 0));
+    assert_eq!(carry, Velocity::new(-0.5, -0.5));
+}
+
+#[test]
+fn clamped() -> () {
+    let velocity = Velocity::new(3.0, 4.0);
+
+    assert_eq!(velocity.clamped(10.0), velocity);
+    assert_eq!(velocity.clamped(2.5), Velocity::new(1.5, 2.0));
+}